@@ -12,11 +12,12 @@
  *   Event:    { "event": string, "data": object }
  *
  * Methods: ping, browser.prepare, browser.startObserving, browser.stopObserving,
- *          browser.close, browser.screenshot
+ *          browser.close, browser.screenshot, browser.screenshotElement,
+ *          browser.validateSelector, browser.perform, browser.snapshot, shutdown
  * Events:  action_captured, browser_disconnected
  */
 
-import { chromium } from "playwright";
+import { chromium, firefox } from "playwright";
 import * as readline from "readline";
 import * as path from "path";
 import * as fs from "fs";
@@ -84,6 +85,21 @@ rl.on("line", async (line) => {
       case "browser.screenshot":
         await handleScreenshot(id, params || {});
         break;
+      case "browser.screenshotElement":
+        await handleScreenshotElement(id, params || {});
+        break;
+      case "browser.validateSelector":
+        await handleValidateSelector(id, params || {});
+        break;
+      case "browser.perform":
+        await handlePerform(id, params || {});
+        break;
+      case "browser.snapshot":
+        await handleSnapshot(id, params || {});
+        break;
+      case "shutdown":
+        handleShutdown(id);
+        break;
       default:
         sendError(id, `Unknown method: ${method}`);
     }
@@ -104,6 +120,8 @@ rl.on("line", async (line) => {
  *      are available. The browser must NOT already be running.
  *   2. **Fresh mode** — Tries Edge → Chrome → bundled Chromium. Clean
  *      browser with no extensions. The user's existing browser stays open.
+ *      When `browser_channel` is "firefox", launches Playwright's Firefox
+ *      instead (Firefox profiles are not supported, so it is always fresh).
  *
  * Does NOT inject any observers yet — the user preps their demo first,
  * then calls browser.startObserving.
@@ -118,7 +136,7 @@ async function handleBrowserPrepare(id, params) {
   const profileDir = params.profile_directory;
   const requestedChannel = params.browser_channel;
 
-  if (userDataDir && profileDir) {
+  if (userDataDir && profileDir && requestedChannel !== "firefox") {
     // ── Profile mode: launch with real user profile ──────────
 
     const channel = requestedChannel || "msedge";
@@ -180,11 +198,17 @@ async function handleBrowserPrepare(id, params) {
   } else {
     // ── Fresh mode: try installed browsers in order ──────────
 
-    const channels = ["msedge", "chrome", ""];
+    const channels =
+      requestedChannel === "firefox" ? ["firefox"] : ["msedge", "chrome", ""];
     let lastError;
 
     for (const channel of channels) {
       try {
+        if (channel === "firefox") {
+          browser = await firefox.launch({ headless: false });
+          browserChannel = "firefox";
+          break;
+        }
         const launchOpts = {
           headless: false,
           args: ["--start-maximized"],
@@ -392,6 +416,174 @@ async function handleScreenshot(id, params) {
   sendResponse(id, { status: "ok", path: outputPath });
 }
 
+/**
+ * Screenshot the element a recorded selector strategy targets.
+ *
+ * Params: `{ strategy, value, output_path }`. Falls back to a full-page
+ * screenshot when the selector doesn't resolve to a visible element.
+ */
+async function handleScreenshotElement(id, params) {
+  if (!page) {
+    sendError(id, "No page available");
+    return;
+  }
+
+  const outputPath = params.output_path;
+  if (!outputPath) {
+    sendError(id, "output_path is required");
+    return;
+  }
+
+  fs.mkdirSync(path.dirname(outputPath), { recursive: true });
+
+  const locator = locatorFor(params.strategy, params.value);
+  if (locator) {
+    try {
+      await locator.first().screenshot({ path: outputPath, timeout: 2000 });
+      sendResponse(id, { status: "ok", path: outputPath, element: true });
+      return;
+    } catch {
+      // Element missing, hidden, or detached — fall back to the page
+    }
+  }
+
+  await page.screenshot({ path: outputPath });
+  sendResponse(id, { status: "ok", path: outputPath, element: false });
+}
+
+/**
+ * Count how many elements a recorded selector strategy matches on the page.
+ *
+ * Params mirror the Rust `SelectorStrategy` serialization:
+ * `{ strategy: "CssSelector", value: "#btn" }`.
+ */
+async function handleValidateSelector(id, params) {
+  if (!page) {
+    sendError(id, "No page available");
+    return;
+  }
+
+  const locator = locatorFor(params.strategy, params.value);
+  if (!locator) {
+    sendError(id, `Unsupported selector strategy: ${params.strategy}`);
+    return;
+  }
+
+  const matchCount = await locator.count();
+  sendResponse(id, { match_count: matchCount });
+}
+
+/**
+ * Perform one replayed action on the page.
+ *
+ * Params: `{ kind, selector?, url?, text?, clear_first?, value?, direction?, amount? }`
+ * where `kind` is navigate | click | type | select | scroll and `selector`
+ * mirrors the Rust `SelectorStrategy` serialization.
+ */
+async function handlePerform(id, params) {
+  if (!page) {
+    sendError(id, "No page available");
+    return;
+  }
+
+  const timeout = 5000;
+
+  if (params.kind === "navigate") {
+    await page.goto(params.url, { waitUntil: "domcontentloaded" });
+    sendResponse(id, { status: "ok" });
+    return;
+  }
+
+  if (params.kind === "scroll") {
+    const amount = Number(params.amount) || 0;
+    const dx = params.direction === "left" ? -amount : params.direction === "right" ? amount : 0;
+    const dy = params.direction === "up" ? -amount : params.direction === "down" ? amount : 0;
+    await page.mouse.wheel(dx, dy);
+    sendResponse(id, { status: "ok" });
+    return;
+  }
+
+  const selector = params.selector || {};
+  const locator = locatorFor(selector.strategy, selector.value);
+  if (!locator) {
+    sendError(id, `Unsupported selector strategy: ${selector.strategy}`);
+    return;
+  }
+  const target = locator.first();
+
+  switch (params.kind) {
+    case "click":
+      await target.click({ timeout });
+      break;
+    case "type":
+      if (params.clear_first) {
+        await target.fill("", { timeout });
+      }
+      await target.pressSequentially(params.text || "", { timeout });
+      break;
+    case "select":
+      await target.selectOption(params.value, { timeout });
+      break;
+    default:
+      sendError(id, `Unsupported action: ${params.kind}`);
+      return;
+  }
+
+  sendResponse(id, { status: "ok" });
+}
+
+/**
+ * Capture the current page's markup for selector healing.
+ *
+ * Scripts, styles, and inline SVG are stripped and the HTML is truncated to
+ * `max_chars` (default 20000) to keep LLM prompts small.
+ */
+async function handleSnapshot(id, params) {
+  if (!page) {
+    sendError(id, "No page available");
+    return;
+  }
+
+  const maxChars = Number(params.max_chars) || 20000;
+  const html = await page.evaluate(() => {
+    const root = document.documentElement.cloneNode(true);
+    root.querySelectorAll("script, style, noscript, svg").forEach((el) => el.remove());
+    return root.outerHTML;
+  });
+
+  sendResponse(id, {
+    url: page.url(),
+    title: await page.title(),
+    html: html.slice(0, maxChars),
+  });
+}
+
+/**
+ * Build a Playwright locator for a recorded selector strategy.
+ * Returns null for strategies that don't apply to web pages.
+ */
+function locatorFor(strategy, value) {
+  if (typeof value !== "string" || !value) return null;
+  const quoted = JSON.stringify(value);
+
+  switch (strategy) {
+    case "CssSelector":
+      return page.locator(value);
+    case "XPath":
+      return page.locator(`xpath=${value}`);
+    case "AccessibilityId":
+      return page.locator(`[id=${quoted}]`);
+    case "AccessibilityName":
+      return page.locator(`[aria-label=${quoted}]`);
+    case "DataTestId":
+      return page.locator(`[data-testid=${quoted}], [data-test-id=${quoted}]`);
+    case "TextContent":
+      return page.getByText(value, { exact: true });
+    default:
+      return null;
+  }
+}
+
 // ── CutReady Welcome Page ───────────────────────────────────────────────────
 
 function getCutReadyWelcomePage() {
@@ -771,6 +963,16 @@ async function cleanup() {
   process.exit(0);
 }
 
+/**
+ * Acknowledge a shutdown request, then close the browser and exit. The
+ * response is flushed first so the Rust side isn't left waiting.
+ */
+function handleShutdown(id) {
+  process.stdout.write(JSON.stringify({ id, result: { status: "ok" } }) + "\n", () => {
+    cleanup();
+  });
+}
+
 process.on("SIGTERM", cleanup);
 process.on("SIGINT", cleanup);
 process.stdin.on("end", cleanup);
@@ -1,14 +1,18 @@
 //! Tauri commands for the AI assistant (chat, model listing, ✨ generation).
 
 use agentive::LocalContextIndex;
+use draftline::tauri_contract as contract;
 use serde::Deserialize;
 
 use crate::engine::agent::llm::{self, ChatMessage, LlmConfig, LlmProvider, ModelInfo};
 use crate::engine::agent::runner::{self, AgentEvent};
+use crate::engine::agent::tools;
 use crate::engine::agent_state::{
     AgentRunDetail, AgentRunSummary, AgentStateMaintenanceResult, AgentStateStore, ChatSessionPage,
     ChatSessionRecord, ChatSessionSummary, ContextAssetInput, ContextAssetScope,
 };
+use crate::engine::project;
+use crate::util::document_events::emit_document_changed;
 use crate::{AgentChatCancellationRegistry, AppState};
 use agentive::azure_oauth::{self, AuthCodeFlowInit, DeviceCodeResponse, TokenResponse};
 use std::collections::HashSet;
@@ -101,6 +105,40 @@ impl Drop for AgentChatCancellationGuard {
     }
 }
 
+/// Pairs document-writing tool calls with their results so successful agent
+/// writes can be announced as `document-changed`.
+#[derive(Default)]
+struct AgentDocumentWrites {
+    pending: Mutex<Vec<(String, String)>>,
+}
+
+impl AgentDocumentWrites {
+    /// Returns `(kind, path)` when `event` is the successful result of a write.
+    fn observe(&self, event: &AgentEvent) -> Option<(&'static str, String)> {
+        let Ok(mut pending) = self.pending.lock() else {
+            return None;
+        };
+        match event {
+            AgentEvent::ToolCall { name, arguments } => {
+                tools::written_document_kind(name)?;
+                let args: serde_json::Value = serde_json::from_str(arguments).ok()?;
+                let path = args.get("path")?.as_str()?;
+                pending.push((name.clone(), path.to_string()));
+                None
+            }
+            AgentEvent::ToolResult { name, result } => {
+                let kind = tools::written_document_kind(name)?;
+                let index = pending
+                    .iter()
+                    .position(|(pending_name, _)| pending_name == name)?;
+                let (_, path) = pending.remove(index);
+                (!tools::is_tool_error(result)).then_some((kind, path))
+            }
+            _ => None,
+        }
+    }
+}
+
 fn cancel_agent_chat_run_in_registry(
     cancellations: &AgentChatCancellationRegistry,
     client_run_id: &str,
@@ -364,6 +402,16 @@ impl From<ProviderConfig> for LlmConfig {
     }
 }
 
+/// The frontend's provider id for an [`LlmProvider`].
+fn provider_key(provider: &LlmProvider) -> &'static str {
+    match provider {
+        LlmProvider::Openai => "openai",
+        LlmProvider::Anthropic => "anthropic",
+        LlmProvider::MicrosoftFoundry => "microsoft_foundry",
+        LlmProvider::AzureOpenai => "azure_openai",
+    }
+}
+
 /// List available models for the configured provider.
 #[auditaur_command(skip_all, err)]
 pub async fn list_models(config: ProviderConfig) -> Result<Vec<ModelInfo>, String> {
@@ -454,6 +502,24 @@ pub async fn list_models(config: ProviderConfig) -> Result<Vec<ModelInfo>, Strin
     }
 }
 
+/// Check that the configured provider answers, for the settings "Test
+/// connection" button. Failures are reported in the result, not as an error.
+#[auditaur_command(skip_all, err)]
+pub async fn test_llm_connection(config: ProviderConfig) -> Result<llm::ConnectionCheck, String> {
+    let provider_name = config.provider.clone();
+    let llm_config: LlmConfig = config.into();
+    let provider = llm::build_provider(&llm_config, None);
+    let check = llm::check_connection(provider, &llm_config).await;
+    log::info!(
+        "[test_llm_connection] provider={} model={} ok={} latency={}ms",
+        provider_name,
+        check.model,
+        check.ok,
+        check.latency_ms
+    );
+    Ok(check)
+}
+
 /// A single chat turn (non-streaming) for quick operations like ✨ field fill.
 #[auditaur_command(skip_all, err)]
 pub async fn agent_chat(
@@ -742,7 +808,7 @@ pub async fn clear_saved_context(state: tauri::State<'_, AppState>) -> Result<us
 /// Agentic chat with function calling — the LLM can read/write project files.
 /// Returns the full conversation (including tool calls) and the final response.
 /// Emits `agent-event` events to the frontend for real-time streaming.
-#[auditaur_command(skip_all, err)]
+#[auditaur_command(skip_all)]
 pub async fn agent_chat_with_tools(
     app: tauri::AppHandle,
     state: tauri::State<'_, AppState>,
@@ -754,7 +820,7 @@ pub async fn agent_chat_with_tools(
     emit_events: Option<bool>,
     allow_mutation_tools: Option<bool>,
     client_run_id: Option<String>,
-) -> Result<AgentChatResult, String> {
+) -> Result<AgentChatResult, contract::TauriCommandError> {
     use tauri::Emitter;
 
     let client_run_id = client_run_id.filter(|id| !id.trim().is_empty());
@@ -765,7 +831,8 @@ pub async fn agent_chat_with_tools(
                 state.agent_chat_cancellations.clone(),
                 client_run_id.clone(),
                 cancellation.clone(),
-            )?;
+            )
+            .map_err(agent_error)?;
             Some(guard)
         }
         None => None,
@@ -795,7 +862,9 @@ pub async fn agent_chat_with_tools(
     }
     let (repo_root, project_root) = {
         let guard = state.current_project.lock().unwrap();
-        let view = guard.as_ref().ok_or("No project open")?;
+        let view = guard
+            .as_ref()
+            .ok_or_else(|| agent_error("No project open".into()))?;
         (view.repo_root.clone(), view.root.clone())
     };
 
@@ -803,20 +872,30 @@ pub async fn agent_chat_with_tools(
 
     let prompts = agent_prompts.unwrap_or_default();
     let agent_id = agent_id.unwrap_or_else(|| "planner".into());
-    let reported_context = config.context_length;
     let vision_mode = config.vision_mode.clone().unwrap_or_else(|| "off".into());
-    let discovered_vision_support = config.model_supports_vision;
     let search_enabled = config.web_access.as_deref() == Some("enabled");
     let max_tool_rounds = config
         .max_tool_rounds
         .unwrap_or(runner::DEFAULT_MAX_TOOL_ROUNDS)
         .clamp(1, 200);
     let mutation_tools_enabled = allow_mutation_tools.unwrap_or(false);
-    let provider_name = config.provider.clone();
     let configured_provider_name = config.provider_name.clone();
     let configured_provider_id = config.provider_id.clone();
-    let model = config.model.clone();
-    let llm_config: LlmConfig = config.into();
+    // A model pinned by the project wins over the global selection; the
+    // frontend's discovered context length and vision support only describe
+    // the global model.
+    let project_llm_config = project::read_llm_config(&repo_root, &project_root);
+    let (provider_name, reported_context, discovered_vision_support) =
+        match project_llm_config.as_ref() {
+            Some(pinned) => (provider_key(&pinned.provider).to_string(), None, None),
+            None => (
+                config.provider.clone(),
+                config.context_length,
+                config.model_supports_vision,
+            ),
+        };
+    let llm_config = llm::prefer_project_config(config.into(), project_llm_config);
+    let model = llm_config.model.clone();
     let context_item_configs = context_items.unwrap_or_default();
 
     // Determine effective vision: user setting AND discovered/static model capability.
@@ -884,7 +963,8 @@ pub async fn agent_chat_with_tools(
     let mut context_items = context_item_configs
         .into_iter()
         .map(|item| item.into_context_item(agent_state.as_ref()))
-        .collect::<Result<Vec<_>, _>>()?;
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(agent_error)?;
     context_items.extend(recalled_context_items(&messages, agent_state.as_ref()));
     log::info!(
         "[agent_chat_with_tools] start run_id={} agent={} provider={} model={} messages={} chars={} budget={}chars reported_context={:?} vision={} web_search={} mutation_tools={} max_tool_rounds={} prompts={}",
@@ -927,6 +1007,7 @@ pub async fn agent_chat_with_tools(
 
     let should_emit_events = emit_events.unwrap_or(true);
     let emit_handle = app.clone();
+    let document_writes = AgentDocumentWrites::default();
     let runner_future = runner::run(
         provider,
         Some(provider_name.clone()),
@@ -941,11 +1022,15 @@ pub async fn agent_chat_with_tools(
         &web_access,
         mutation_tools_enabled,
         max_tool_rounds,
+        reported_context,
         context_items,
         Some(run_id.clone()),
         agent_state.clone(),
         cancellation.clone(),
         move |event: AgentEvent| {
+            if let Some((kind, path)) = document_writes.observe(&event) {
+                emit_document_changed(&emit_handle, kind, &path);
+            }
             if should_emit_events {
                 let payload = agent_event_payload(&event, client_run_id.as_deref());
                 let _ = emit_handle.emit("agent-event", payload);
@@ -954,7 +1039,7 @@ pub async fn agent_chat_with_tools(
     );
     let runner_result = runner_future.await;
     let runner_result = if cancellation.is_cancelled() {
-        Err(AGENT_RUN_CANCELLED_ERROR.into())
+        Err(runner::RunError::Failed(AGENT_RUN_CANCELLED_ERROR.into()))
     } else {
         runner_result
     };
@@ -992,7 +1077,10 @@ pub async fn agent_chat_with_tools(
         }
         Err(err) => {
             let elapsed_ms = started.elapsed().as_millis();
-            let cancelled = err == AGENT_RUN_CANCELLED_ERROR;
+            let cancelled = matches!(
+                &err,
+                runner::RunError::Failed(message) if message == AGENT_RUN_CANCELLED_ERROR
+            );
             if cancelled {
                 log::info!(
                     "[agent_chat_with_tools] cancelled provider={} model={} elapsed={}ms",
@@ -1021,7 +1109,7 @@ pub async fn agent_chat_with_tools(
                     "model": model,
                     "run_id": &run_id,
                     "elapsed_ms": elapsed_ms,
-                    "error": err,
+                    "error": err.to_string(),
                 }),
             );
             if let Some(agent_state) = &agent_state {
@@ -1033,7 +1121,7 @@ pub async fn agent_chat_with_tools(
                     );
                 }
             }
-            return Err(err);
+            return Err(run_error(err));
         }
     };
 
@@ -1043,6 +1131,30 @@ pub async fn agent_chat_with_tools(
     })
 }
 
+/// A context overflow carries `needed` and `limit` (in tokens) so the
+/// frontend can suggest a larger model; anything else is just its message.
+fn run_error(error: runner::RunError) -> contract::TauriCommandError {
+    match error {
+        runner::RunError::ContextExceeded(exceeded) => contract::TauriCommandError {
+            code: "context_exceeded".into(),
+            message: exceeded.to_string(),
+            details: Some(serde_json::json!({
+                "needed": exceeded.needed,
+                "limit": exceeded.limit,
+            })),
+        },
+        runner::RunError::Failed(message) => agent_error(message),
+    }
+}
+
+fn agent_error(message: String) -> contract::TauriCommandError {
+    contract::TauriCommandError {
+        code: "agent_error".into(),
+        message,
+        details: None,
+    }
+}
+
 /// Request cancellation of the active chat run associated with a frontend client run ID.
 #[auditaur_command(skip_all, err)]
 pub async fn cancel_agent_chat_run(
@@ -1402,6 +1514,34 @@ mod tests {
         assert_eq!(config.provider, LlmProvider::AzureOpenai);
     }
 
+    #[test]
+    fn document_writes_report_only_successful_write_results() {
+        let writes = AgentDocumentWrites::default();
+        let call = |path: &str| AgentEvent::ToolCall {
+            name: "write_note".into(),
+            arguments: serde_json::json!({ "path": path }).to_string(),
+        };
+        let result = |text: &str| AgentEvent::ToolResult {
+            name: "write_note".into(),
+            result: text.into(),
+        };
+
+        assert_eq!(writes.observe(&call("notes/a.md")), None);
+        assert_eq!(
+            writes.observe(&result("Wrote notes/a.md")),
+            Some(("note", "notes/a.md".to_string()))
+        );
+        assert_eq!(writes.observe(&call("notes/b.md")), None);
+        assert_eq!(writes.observe(&result("Error: locked")), None);
+        assert_eq!(
+            writes.observe(&AgentEvent::ToolCall {
+                name: "read_note".into(),
+                arguments: serde_json::json!({ "path": "notes/a.md" }).to_string(),
+            }),
+            None
+        );
+    }
+
     #[tokio::test]
     async fn cancellation_registry_cancels_only_the_matching_client_run() {
         let cancellations = Arc::new(Mutex::new(std::collections::HashMap::new()));
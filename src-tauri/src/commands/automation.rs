@@ -1,3 +1,44 @@
 //! Tauri commands for the automation engine (replay actions).
-//!
-//! Stub — will be implemented in Phase 4.
+
+use tauri::{AppHandle, Emitter, State};
+
+use crate::commands::agent::ProviderConfig;
+use crate::engine::agent::llm::{self, LlmConfig};
+use crate::engine::{automation, interaction};
+use crate::AppState;
+
+/// Helper: get the project root from current state.
+fn project_root(state: &AppState) -> Result<std::path::PathBuf, String> {
+    let current = state.current_project.lock().map_err(|e| e.to_string())?;
+    let view = current.as_ref().ok_or("No project is currently open")?;
+    Ok(view.root.clone())
+}
+
+/// Replay a saved session in the currently prepared browser.
+///
+/// Emits `replay-step` after each action so the UI can show progress, and
+/// returns the full report including every step whose selectors broke. When
+/// `heal_with` is set, broken browser steps are retried with selectors healed
+/// by that provider.
+#[tauri::command]
+pub async fn replay_session(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    session_id: String,
+    heal_with: Option<ProviderConfig>,
+) -> Result<automation::ReplayReport, String> {
+    let root = project_root(&state)?;
+    let session = interaction::load_session(&root, &session_id).map_err(|e| e.to_string())?;
+    let healer = heal_with.map(|config| {
+        let llm_config: LlmConfig = config.into();
+        llm::build_provider(&llm_config, None)
+    });
+
+    let browser_guard = state.browser.lock().await;
+    let browser = browser_guard.as_ref().ok_or("No browser prepared")?;
+    automation::replay(&*browser.sidecar, &session, healer, |step| {
+        let _ = app.emit("replay-step", step);
+    })
+    .await
+    .map_err(|e| e.to_string())
+}
@@ -283,7 +283,7 @@ pub async fn get_auditaur_diagnostics() -> Result<AuditaurDiagnosticsSummary, St
             session_size_bytes: discovery
                 .database_path
                 .parent()
-                .and_then(|path| directory_size(path).ok()),
+                .and_then(|path| project::directory_size(path).ok()),
             database_path: sanitize_diagnostic_text(&discovery.database_path.display().to_string()),
             last_heartbeat_at: discovery.last_heartbeat_at,
         }),
@@ -317,41 +317,8 @@ pub async fn clear_auditaur_logs() -> Result<ClearAuditaurLogsResult, String> {
     let mut skipped_active_session = false;
     let mut removable = Vec::new();
 
-    let entries = std::fs::read_dir(&apps_dir)
-        .map_err(|e| format!("Could not read diagnostics discovery directory: {e}"))?;
-    for entry in entries {
-        let entry =
-            entry.map_err(|e| format!("Could not read diagnostics discovery entry: {e}"))?;
-        if entry.path().extension().and_then(|ext| ext.to_str()) != Some("json") {
-            continue;
-        }
-        let content = match std::fs::read_to_string(entry.path()) {
-            Ok(content) => content,
-            Err(error) => {
-                notes.push(format!(
-                    "Skipping unreadable discovery file {}: {error}",
-                    entry.path().display()
-                ));
-                continue;
-            }
-        };
-        let mut discovery: AuditaurDiscovery = match serde_json::from_str(&content) {
-            Ok(discovery) => discovery,
-            Err(error) => {
-                notes.push(format!(
-                    "Skipping invalid discovery file {}: {error}",
-                    entry.path().display()
-                ));
-                continue;
-            }
-        };
-        discovery.discovery_path = Some(entry.path());
-        if discovery.service_name != "cutready" {
-            continue;
-        }
-        if discovery.pid == Some(current_pid)
-            && heartbeat_is_fresh(discovery.last_heartbeat_at.as_deref())
-        {
+    for discovery in read_cutready_discoveries(&apps_dir, &mut notes)? {
+        if is_active_session(&discovery, current_pid) {
             skipped_active_session = true;
             continue;
         }
@@ -359,49 +326,11 @@ pub async fn clear_auditaur_logs() -> Result<ClearAuditaurLogsResult, String> {
     }
 
     for discovery in removable {
-        let session_dir = discovery
-            .database_path
-            .parent()
-            .map(Path::to_path_buf)
-            .unwrap_or_else(|| sessions_dir.join(&discovery.session_id));
-        if !is_path_inside(&session_dir, &sessions_dir) {
-            notes.push(format!(
-                "Skipped diagnostics session outside sessions directory: {}",
-                session_dir.display()
-            ));
-            continue;
-        }
-        let session_bytes = directory_size(&session_dir).unwrap_or_else(|error| {
-            notes.push(format!(
-                "Could not size diagnostics session {}: {error}",
-                session_dir.display()
-            ));
-            0
-        });
-        if session_dir.exists() {
-            match std::fs::remove_dir_all(&session_dir) {
-                Ok(()) => {
-                    removed_sessions += 1;
-                    removed_bytes += session_bytes;
-                }
-                Err(error) => {
-                    notes.push(format!(
-                        "Could not remove diagnostics session {}: {error}",
-                        session_dir.display()
-                    ));
-                    continue;
-                }
-            }
-        }
-        if let Some(discovery_path) = discovery.discovery_path {
-            if is_path_inside(&discovery_path, &apps_dir) {
-                if let Err(error) = std::fs::remove_file(&discovery_path) {
-                    notes.push(format!(
-                        "Could not remove diagnostics discovery file {}: {error}",
-                        discovery_path.display()
-                    ));
-                }
-            }
+        if let Some(session_bytes) =
+            remove_auditaur_session(discovery, &sessions_dir, &apps_dir, &mut notes)
+        {
+            removed_sessions += 1;
+            removed_bytes += session_bytes;
         }
     }
 
@@ -417,6 +346,52 @@ pub async fn clear_auditaur_logs() -> Result<ClearAuditaurLogsResult, String> {
     })
 }
 
+/// Path of the current diagnostics session database, for attaching to bug reports.
+#[auditaur_command(skip_all, err)]
+pub async fn get_log_path() -> Result<Option<String>, String> {
+    let mut notes = Vec::new();
+    Ok(find_current_auditaur_discovery(&mut notes)?
+        .map(|discovery| discovery.database_path.display().to_string()))
+}
+
+/// Remove all but the newest `MAX_RETAINED_AUDITAUR_SESSIONS` CutReady
+/// diagnostics sessions. Each session is already size-capped by Auditaur, so
+/// this bounds total log storage. Called once at startup; failures are logged.
+pub fn prune_auditaur_sessions() {
+    let auditaur_root = auditaur_root();
+    let apps_dir = auditaur_root.join("apps");
+    let sessions_dir = auditaur_root.join("sessions");
+    if !apps_dir.exists() || !sessions_dir.exists() {
+        return;
+    }
+
+    let mut notes = Vec::new();
+    let discoveries = match read_cutready_discoveries(&apps_dir, &mut notes) {
+        Ok(discoveries) => discoveries,
+        Err(error) => {
+            tracing::warn!(error = %error, "Could not prune diagnostics sessions");
+            return;
+        }
+    };
+    let stale = sessions_beyond_retention(
+        discoveries,
+        MAX_RETAINED_AUDITAUR_SESSIONS,
+        std::process::id(),
+    );
+    let mut removed_sessions = 0;
+    for discovery in stale {
+        if remove_auditaur_session(discovery, &sessions_dir, &apps_dir, &mut notes).is_some() {
+            removed_sessions += 1;
+        }
+    }
+    for note in &notes {
+        tracing::warn!(note = %sanitize_diagnostic_text(note), "Diagnostics session pruning");
+    }
+    if removed_sessions > 0 {
+        tracing::info!(removed_sessions, "Pruned old diagnostics sessions");
+    }
+}
+
 #[auditaur_command(skip_all, err)]
 pub async fn dump_diagnostics(
     sketch_path: Option<String>,
@@ -490,28 +465,6 @@ fn file_size(path: &Path) -> Option<u64> {
     std::fs::metadata(path).ok().map(|metadata| metadata.len())
 }
 
-fn directory_size(path: &Path) -> Result<u64, String> {
-    let mut total = 0;
-    if !path.exists() {
-        return Ok(0);
-    }
-    let entries = std::fs::read_dir(path)
-        .map_err(|e| format!("Could not read directory {}: {e}", path.display()))?;
-    for entry in entries {
-        let entry = entry
-            .map_err(|e| format!("Could not read directory entry in {}: {e}", path.display()))?;
-        let metadata = entry
-            .metadata()
-            .map_err(|e| format!("Could not inspect {}: {e}", entry.path().display()))?;
-        if metadata.is_dir() {
-            total += directory_size(&entry.path())?;
-        } else {
-            total += metadata.len();
-        }
-    }
-    Ok(total)
-}
-
 fn is_path_inside(path: &Path, root: &Path) -> bool {
     let Ok(path) = path.canonicalize() else {
         return false;
@@ -589,6 +542,129 @@ fn find_current_auditaur_discovery(
     Ok(fallback)
 }
 
+/// Number of CutReady diagnostics sessions kept on disk across launches.
+const MAX_RETAINED_AUDITAUR_SESSIONS: usize = 5;
+
+fn is_active_session(discovery: &AuditaurDiscovery, current_pid: u32) -> bool {
+    discovery.pid == Some(current_pid) && heartbeat_is_fresh(discovery.last_heartbeat_at.as_deref())
+}
+
+/// Sessions to delete so that at most `keep` remain, newest first by heartbeat.
+/// The active session is always kept and counts toward the limit.
+fn sessions_beyond_retention(
+    mut discoveries: Vec<AuditaurDiscovery>,
+    keep: usize,
+    current_pid: u32,
+) -> Vec<AuditaurDiscovery> {
+    discoveries.sort_by(|left, right| {
+        is_active_session(right, current_pid)
+            .cmp(&is_active_session(left, current_pid))
+            .then_with(|| right.last_heartbeat_at.cmp(&left.last_heartbeat_at))
+    });
+    discoveries
+        .into_iter()
+        .enumerate()
+        .filter(|(index, discovery)| *index >= keep && !is_active_session(discovery, current_pid))
+        .map(|(_, discovery)| discovery)
+        .collect()
+}
+
+/// Read every CutReady discovery file in `apps_dir`, noting unreadable ones.
+fn read_cutready_discoveries(
+    apps_dir: &Path,
+    notes: &mut Vec<String>,
+) -> Result<Vec<AuditaurDiscovery>, String> {
+    let mut discoveries = Vec::new();
+    let entries = std::fs::read_dir(apps_dir)
+        .map_err(|e| format!("Could not read diagnostics discovery directory: {e}"))?;
+    for entry in entries {
+        let entry =
+            entry.map_err(|e| format!("Could not read diagnostics discovery entry: {e}"))?;
+        if entry.path().extension().and_then(|ext| ext.to_str()) != Some("json") {
+            continue;
+        }
+        let content = match std::fs::read_to_string(entry.path()) {
+            Ok(content) => content,
+            Err(error) => {
+                notes.push(format!(
+                    "Skipping unreadable discovery file {}: {error}",
+                    entry.path().display()
+                ));
+                continue;
+            }
+        };
+        let mut discovery: AuditaurDiscovery = match serde_json::from_str(&content) {
+            Ok(discovery) => discovery,
+            Err(error) => {
+                notes.push(format!(
+                    "Skipping invalid discovery file {}: {error}",
+                    entry.path().display()
+                ));
+                continue;
+            }
+        };
+        discovery.discovery_path = Some(entry.path());
+        if discovery.service_name == "cutready" {
+            discoveries.push(discovery);
+        }
+    }
+    Ok(discoveries)
+}
+
+/// Delete a session directory and its discovery file. Returns the bytes freed,
+/// or `None` when the session was skipped or could not be removed.
+fn remove_auditaur_session(
+    discovery: AuditaurDiscovery,
+    sessions_dir: &Path,
+    apps_dir: &Path,
+    notes: &mut Vec<String>,
+) -> Option<u64> {
+    let session_dir = discovery
+        .database_path
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| sessions_dir.join(&discovery.session_id));
+    if !is_path_inside(&session_dir, sessions_dir) {
+        notes.push(format!(
+            "Skipped diagnostics session outside sessions directory: {}",
+            session_dir.display()
+        ));
+        return None;
+    }
+    let session_bytes = project::directory_size(&session_dir).unwrap_or_else(|error| {
+        notes.push(format!(
+            "Could not size diagnostics session {}: {error}",
+            session_dir.display()
+        ));
+        0
+    });
+    let removed = if session_dir.exists() {
+        match std::fs::remove_dir_all(&session_dir) {
+            Ok(()) => Some(session_bytes),
+            Err(error) => {
+                notes.push(format!(
+                    "Could not remove diagnostics session {}: {error}",
+                    session_dir.display()
+                ));
+                return None;
+            }
+        }
+    } else {
+        None
+    };
+    if let Some(discovery_path) = discovery.discovery_path {
+        if is_path_inside(&discovery_path, apps_dir) {
+            if let Err(error) = std::fs::remove_file(&discovery_path) {
+                notes.push(format!(
+                    "Could not remove diagnostics discovery file {}: {error}",
+                    discovery_path.display()
+                ));
+            }
+        }
+    }
+    removed
+}
+
 fn heartbeat_is_fresh(last_heartbeat_at: Option<&str>) -> bool {
     let Some(last_heartbeat_at) = last_heartbeat_at else {
         return false;
@@ -820,7 +896,19 @@ fn object_keys(value: &Value, key: &str) -> Vec<String> {
 
 #[cfg(test)]
 mod tests {
-    use super::parse_diagnostics_row_target;
+    use super::{parse_diagnostics_row_target, sessions_beyond_retention, AuditaurDiscovery};
+
+    fn discovery(session_id: &str, pid: u32, last_heartbeat_at: String) -> AuditaurDiscovery {
+        AuditaurDiscovery {
+            session_id: session_id.to_string(),
+            service_name: "cutready".to_string(),
+            app_identifier: None,
+            pid: Some(pid),
+            database_path: std::path::PathBuf::from(session_id).join("auditaur.db"),
+            last_heartbeat_at: Some(last_heartbeat_at),
+            discovery_path: None,
+        }
+    }
 
     #[test]
     fn diagnostics_row_number_is_one_based() {
@@ -852,4 +940,25 @@ mod tests {
             Some(1)
         );
     }
+
+    #[test]
+    fn session_retention_keeps_newest_and_active() {
+        let now = chrono::Utc::now();
+        let at = |minutes_ago: i64| (now - chrono::Duration::minutes(minutes_ago)).to_rfc3339();
+        let current_pid = std::process::id();
+        let sessions = vec![
+            discovery("old", 1, at(300)),
+            discovery("active", current_pid, at(0)),
+            discovery("newer", 2, at(10)),
+            discovery("oldest", 3, at(900)),
+        ];
+
+        let mut stale: Vec<String> = sessions_beyond_retention(sessions, 2, current_pid)
+            .into_iter()
+            .map(|discovery| discovery.session_id)
+            .collect();
+        stale.sort();
+
+        assert_eq!(stale, vec!["old".to_string(), "oldest".to_string()]);
+    }
 }
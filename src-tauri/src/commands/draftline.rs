@@ -21,6 +21,7 @@ use crate::engine::draftline_adapter::{
     cutready_content_policy, cutready_remote_options, is_github_remote_url,
     CutReadyDraftlineAdapter,
 };
+use crate::engine::project;
 use crate::{AppState, ProjectLock};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -52,6 +53,25 @@ pub struct DraftlineAddRemoteRequest {
     pub url: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct DraftlineFileHistoryRequest {
+    pub workspace_path: PathBuf,
+    pub path: PathBuf,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DraftlineVersionTagRequest {
+    pub workspace_path: PathBuf,
+    pub version_id: String,
+    pub tag: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DraftlineDeleteVersionTagRequest {
+    pub workspace_path: PathBuf,
+    pub tag: String,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct DraftlineSquashVersionsRequest {
     pub workspace_path: PathBuf,
@@ -140,6 +160,38 @@ fn build_draftline_context(
     Ok(context)
 }
 
+/// Emitted after an operation rewrites the working tree to another point in
+/// history, so open editors can reload their documents.
+pub const TIMELINE_CHANGED_EVENT: &str = "timeline-changed";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TimelineChanged {
+    pub variation: String,
+    pub head: Option<draftline::VersionId>,
+}
+
+/// Report the active variation and its newest version. Call only once the
+/// working tree has been rewritten.
+fn emit_timeline_changed(app: &AppHandle, workspace_path: &Path) {
+    let adapter = match CutReadyDraftlineAdapter::open_project(workspace_path) {
+        Ok(adapter) => adapter,
+        Err(error) => {
+            tracing::warn!("failed to read timeline after change: {error}");
+            return;
+        }
+    };
+    let payload = TimelineChanged {
+        variation: adapter.current_variation().unwrap_or_default(),
+        head: adapter
+            .versions()
+            .ok()
+            .and_then(|versions| versions.first().map(|version| version.id().clone())),
+    };
+    if let Err(error) = app.emit(TIMELINE_CHANGED_EVENT, payload) {
+        tracing::warn!("failed to emit {TIMELINE_CHANGED_EVENT}: {error}");
+    }
+}
+
 fn remote_endpoint_to_dto(remote: RemoteEndpoint) -> DraftlineRemoteEndpointDto {
     DraftlineRemoteEndpointDto {
         name: remote.name,
@@ -211,6 +263,32 @@ fn context_for_workspace(
     })
 }
 
+/// Stable error code for a Draftline failure, so the UI can branch on it.
+fn draftline_error_code(error: &draftline::DraftlineError) -> &'static str {
+    match error {
+        draftline::DraftlineError::PreflightFailed(_) => "preflight_failed",
+        draftline::DraftlineError::SyncNeedsMerge(_) => "sync_needs_merge",
+        draftline::DraftlineError::InvalidHistoryCleanup(_) => "invalid_history_cleanup",
+        _ => "draftline_error",
+    }
+}
+
+fn command_error(error: draftline::DraftlineError) -> contract::TauriCommandError {
+    contract::TauriCommandError {
+        code: draftline_error_code(&error).into(),
+        message: error.to_string(),
+        details: None,
+    }
+}
+
+fn remote_url_error(message: String) -> contract::TauriCommandError {
+    contract::TauriCommandError {
+        code: "remote_url_credentials".into(),
+        message,
+        details: None,
+    }
+}
+
 #[auditaur_command(skip_all)]
 pub async fn open_workspace(
     request: contract::WorkspaceRequest,
@@ -248,6 +326,18 @@ pub async fn adopt_workspace(
     ))
 }
 
+/// Adopt the git repository at `workspace_path` under the project lock, for
+/// commands that open a folder rather than receive a request.
+pub(crate) async fn adopt_workspace_at(
+    workspace_path: &Path,
+    app: AppHandle,
+    lock: &ProjectLock,
+) -> Result<(), String> {
+    let _guard = lock.0.lock().await;
+    let mut context = build_draftline_context(workspace_path, Some(app))?;
+    project::adopt_repo(workspace_path, &mut context).map_err(|e| e.to_string())
+}
+
 #[auditaur_command(skip_all)]
 pub async fn inspect_workspace(
     request: contract::WorkspaceRequest,
@@ -300,72 +390,113 @@ pub async fn rename_variation(
     ))
 }
 
-#[auditaur_command(skip_all, err)]
+#[auditaur_command(skip_all)]
 pub async fn delete_variation(
     request: DraftlineVariationRequest,
     lock: State<'_, ProjectLock>,
-) -> Result<(), String> {
+) -> contract::TauriCommandResult<()> {
     let _guard = lock.0.lock().await;
-    let adapter = CutReadyDraftlineAdapter::open_project(&request.workspace_path)
-        .map_err(|error| error.to_string())?;
+    let adapter =
+        CutReadyDraftlineAdapter::open_project(&request.workspace_path).map_err(command_error)?;
     adapter
         .delete_variation(&request.variation)
-        .map_err(|error| error.to_string())
+        .map_err(command_error)
 }
 
-#[auditaur_command(skip_all, err)]
+#[auditaur_command(skip_all)]
 pub async fn preflight_switch_variation(
     request: DraftlineSwitchVariationRequest,
-) -> Result<PreflightReport, String> {
-    let adapter = CutReadyDraftlineAdapter::open_project(&request.workspace_path)
-        .map_err(|error| error.to_string())?;
+) -> contract::TauriCommandResult<PreflightReport> {
+    let adapter =
+        CutReadyDraftlineAdapter::open_project(&request.workspace_path).map_err(command_error)?;
     adapter
         .preflight_switch_variation(&request.variation_id)
-        .map_err(|error| error.to_string())
+        .map_err(command_error)
 }
 
-#[auditaur_command(skip_all, err)]
+#[auditaur_command(skip_all)]
 pub async fn switch_variation(
     request: DraftlineSwitchVariationRequest,
+    app: AppHandle,
     lock: State<'_, ProjectLock>,
-) -> Result<Variation, String> {
+) -> contract::TauriCommandResult<Variation> {
     let _guard = lock.0.lock().await;
-    let adapter = CutReadyDraftlineAdapter::open_project(&request.workspace_path)
-        .map_err(|error| error.to_string())?;
-    adapter
+    let adapter =
+        CutReadyDraftlineAdapter::open_project(&request.workspace_path).map_err(command_error)?;
+    let variation = adapter
         .switch_variation_with_policy(
             &request.variation_id,
             switch_policy_from_input(request.policy),
         )
-        .map_err(|error| error.to_string())
+        .map_err(command_error)?;
+    emit_timeline_changed(&app, &request.workspace_path);
+    Ok(variation)
 }
 
-#[auditaur_command(skip_all, err)]
+#[auditaur_command(skip_all)]
 pub async fn add_remote(
     request: DraftlineAddRemoteRequest,
     lock: State<'_, ProjectLock>,
-) -> Result<DraftlineRemoteEndpointDto, String> {
-    reject_remote_url_credentials(&request.url)?;
+) -> contract::TauriCommandResult<DraftlineRemoteEndpointDto> {
+    reject_remote_url_credentials(&request.url).map_err(remote_url_error)?;
     let _guard = lock.0.lock().await;
-    let adapter = CutReadyDraftlineAdapter::open_project(&request.workspace_path)
-        .map_err(|error| error.to_string())?;
+    let adapter =
+        CutReadyDraftlineAdapter::open_project(&request.workspace_path).map_err(command_error)?;
     adapter
         .add_remote(&request.name, &request.url)
         .map(remote_endpoint_to_dto)
-        .map_err(|error| error.to_string())
+        .map_err(command_error)
 }
 
-#[auditaur_command(skip_all, err)]
+#[auditaur_command(skip_all)]
 pub async fn squash_versions(
     request: DraftlineSquashVersionsRequest,
     lock: State<'_, ProjectLock>,
-) -> Result<Version, String> {
+) -> contract::TauriCommandResult<Version> {
     let _guard = lock.0.lock().await;
-    let adapter = CutReadyDraftlineAdapter::open_project(&request.workspace_path)
-        .map_err(|error| error.to_string())?;
+    let adapter =
+        CutReadyDraftlineAdapter::open_project(&request.workspace_path).map_err(command_error)?;
     adapter
         .squash_versions(request.count, &request.label)
-        .map_err(|error| error.to_string())
+        .map_err(command_error)
+}
+
+/// List the versions of the current variation that changed a single file.
+#[auditaur_command(skip_all)]
+pub async fn file_history(
+    request: DraftlineFileHistoryRequest,
+) -> contract::TauriCommandResult<Vec<Version>> {
+    let adapter =
+        CutReadyDraftlineAdapter::open_project(&request.workspace_path).map_err(command_error)?;
+    adapter.file_history(&request.path).map_err(command_error)
+}
+
+/// List milestone tags attached to versions in the workspace.
+#[auditaur_command(skip_all, err)]
+pub async fn list_version_tags(
+    request: contract::WorkspaceRequest,
+) -> Result<Vec<project::VersionTag>, String> {
+    project::list_version_tags(&request.workspace_path).map_err(|e| e.to_string())
+}
+
+/// Tag a version as a named milestone, moving the tag if it already exists.
+#[auditaur_command(skip_all, err)]
+pub async fn tag_version(
+    request: DraftlineVersionTagRequest,
+    lock: State<'_, ProjectLock>,
+) -> Result<Vec<project::VersionTag>, String> {
+    let _guard = lock.0.lock().await;
+    project::tag_version(&request.workspace_path, &request.version_id, &request.tag)
+        .map_err(|e| e.to_string())
+}
+
+#[auditaur_command(skip_all, err)]
+pub async fn delete_version_tag(
+    request: DraftlineDeleteVersionTagRequest,
+    lock: State<'_, ProjectLock>,
+) -> Result<Vec<project::VersionTag>, String> {
+    let _guard = lock.0.lock().await;
+    project::delete_version_tag(&request.workspace_path, &request.tag).map_err(|e| e.to_string())
 }
 
 #[auditaur_command(skip_all)]
@@ -825,11 +956,14 @@ pub async fn restore_version_as_new_save(
     lock: State<'_, ProjectLock>,
 ) -> contract::TauriCommandResult<contract::RestoreVersionResult> {
     let _guard = lock.0.lock().await;
-    let mut context = context_for_workspace(&request.workspace_path, app)?;
-    contract::into_tauri_result(contract::restore_version_as_new_save_with_context(
+    let workspace_path = request.workspace_path.clone();
+    let mut context = context_for_workspace(&workspace_path, app.clone())?;
+    let result = contract::into_tauri_result(contract::restore_version_as_new_save_with_context(
         &mut context,
         request,
-    ))
+    ))?;
+    emit_timeline_changed(&app, &workspace_path);
+    Ok(result)
 }
 
 #[auditaur_command(skip_all)]
@@ -839,10 +973,13 @@ pub async fn restore_version_as_new_save_to_variation(
     lock: State<'_, ProjectLock>,
 ) -> contract::TauriCommandResult<contract::TargetedRestoreVersionCommandResult> {
     let _guard = lock.0.lock().await;
-    let mut context = context_for_workspace(&request.workspace_path, app)?;
-    contract::into_tauri_result(
+    let workspace_path = request.workspace_path.clone();
+    let mut context = context_for_workspace(&workspace_path, app.clone())?;
+    let result = contract::into_tauri_result(
         contract::restore_version_as_new_save_to_variation_with_context(&mut context, request),
-    )
+    )?;
+    emit_timeline_changed(&app, &workspace_path);
+    Ok(result)
 }
 
 #[auditaur_command(skip_all)]
@@ -1139,4 +1276,17 @@ mod tests {
             "https://github.com/owner/repo.git"
         );
     }
+
+    #[test]
+    fn draftline_errors_map_to_stable_codes() {
+        let error = command_error(draftline::DraftlineError::InvalidHistoryCleanup(
+            "crosses merge commit".into(),
+        ));
+        assert_eq!(error.code, "invalid_history_cleanup");
+        assert!(error.message.contains("crosses merge commit"));
+
+        let error = remote_url_error("no credentials".into());
+        assert_eq!(error.code, "remote_url_credentials");
+        assert_eq!(error.message, "no credentials");
+    }
 }
@@ -77,7 +77,12 @@ pub async fn import_docx(
     let final_path = resolve_import_path(&root, &relative_path, "md", conflict)?;
     let abs_final = project::safe_resolve(&root, &final_path).map_err(|e| e.to_string())?;
 
-    project::write_note(&abs_final, &markdown).map_err(|e| e.to_string())?;
+    project::write_note(
+        &abs_final,
+        &markdown,
+        super::note::normalize_line_endings(&state),
+    )
+    .map_err(|e| e.to_string())?;
     Ok(final_path)
 }
 
@@ -110,7 +115,12 @@ pub async fn import_pdf(
     let final_path = resolve_import_path(&root, &relative_path, "md", conflict)?;
     let abs_final = project::safe_resolve(&root, &final_path).map_err(|e| e.to_string())?;
 
-    project::write_note(&abs_final, &markdown).map_err(|e| e.to_string())?;
+    project::write_note(
+        &abs_final,
+        &markdown,
+        super::note::normalize_line_endings(&state),
+    )
+    .map_err(|e| e.to_string())?;
     Ok(final_path)
 }
 
@@ -143,7 +153,12 @@ pub async fn import_pptx(
     let final_path = resolve_import_path(&root, &relative_path, "md", conflict)?;
     let abs_final = project::safe_resolve(&root, &final_path).map_err(|e| e.to_string())?;
 
-    project::write_note(&abs_final, &markdown).map_err(|e| e.to_string())?;
+    project::write_note(
+        &abs_final,
+        &markdown,
+        super::note::normalize_line_endings(&state),
+    )
+    .map_err(|e| e.to_string())?;
     Ok(final_path)
 }
 
@@ -183,7 +198,12 @@ pub async fn import_markdown(
     }
 
     let abs_final = project::safe_resolve(&root, &final_path).map_err(|e| e.to_string())?;
-    project::write_note(&abs_final, &content).map_err(|e| e.to_string())?;
+    project::write_note(
+        &abs_final,
+        &content,
+        super::note::normalize_line_endings(&state),
+    )
+    .map_err(|e| e.to_string())?;
     Ok(final_path)
 }
 
@@ -286,12 +306,20 @@ fn slugify(name: &str) -> String {
 
 /// Copy a single asset file from source root to dest root if it exists.
 /// When `overwrite` is true, replaces existing files.
+///
+/// Asset paths come from imported file content, so both sides go through
+/// `safe_resolve`; references that escape either root are skipped.
 fn copy_asset(source_root: &Path, dest_root: &Path, relative_path: &str, overwrite: bool) {
-    let src = source_root.join(relative_path);
+    let (Ok(src), Ok(dest)) = (
+        project::safe_resolve(source_root, relative_path),
+        project::safe_resolve(dest_root, relative_path),
+    ) else {
+        log::warn!("Skipping asset outside the project: {relative_path}");
+        return;
+    };
     if !src.exists() {
         return;
     }
-    let dest = dest_root.join(relative_path);
     if dest.exists() && !overwrite {
         return;
     }
@@ -7,14 +7,43 @@
 //!   3. `disconnect_browser` — Close the browser when done.
 
 use std::sync::Arc;
+use std::time::Instant;
 
 use chrono::Utc;
 use tauri::State;
+use tauri_plugin_store::StoreExt;
 
-use crate::engine::interaction;
-use crate::models::session::{CapturedAction, RecordedSession, RecordingMode};
+use crate::engine::{automation, cleanup, interaction, project};
+use crate::models::action::{Action, ActionMetadata, SelectorStrategy};
+use crate::models::session::{CapturedAction, RecordedSession, RecordingMode, SessionSummary};
+use crate::util::sidecar::SidecarManager;
 use crate::{AppState, BrowserConnection, RecordingInner};
 
+const UI_STORE_FILE: &str = "ui-settings.json";
+const BROWSER_SELECTION_KEY_PREFIX: &str = "last_browser_selection:";
+
+/// The browser options last passed to `prepare_browser` for a project.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BrowserSelection {
+    pub browser_channel: Option<String>,
+    pub user_data_dir: Option<String>,
+    pub profile_directory: Option<String>,
+}
+
+fn browser_selection_key(project_root: &std::path::Path) -> String {
+    format!(
+        "{BROWSER_SELECTION_KEY_PREFIX}{}",
+        project_root.to_string_lossy()
+    )
+}
+
+/// Helper: get the project root from current state.
+fn project_root(state: &AppState) -> Result<std::path::PathBuf, String> {
+    let current = state.current_project.lock().map_err(|e| e.to_string())?;
+    let view = current.as_ref().ok_or("No project is currently open")?;
+    Ok(view.root.clone())
+}
+
 /// Detect browser profiles available on the system.
 ///
 /// Reads Edge and Chrome `Local State` files to find all profiles.
@@ -31,15 +60,24 @@ pub fn check_browsers_running() -> interaction::BrowserRunningStatus {
     interaction::check_browsers_running()
 }
 
+/// Detect profiles and running browsers in one call and recommend a channel
+/// whose profile isn't locked by an open window.
+#[tauri::command]
+pub fn recorder_readiness() -> interaction::RecorderReadiness {
+    interaction::recorder_readiness()
+}
+
 /// Launch a recording browser.
 ///
 /// When `user_data_dir` and `profile_directory` are provided, launches with
 /// the user's real browser profile (extensions, passwords, bookmarks).
 /// Otherwise tries Edge → Chrome → bundled Chromium in fresh mode.
+/// Passing `browser_channel: "firefox"` launches Firefox in a fresh context.
 ///
-/// Returns the browser channel used ("chrome", "msedge", or "chromium").
+/// Returns the browser channel used ("chrome", "msedge", "chromium", or "firefox").
 #[tauri::command]
 pub async fn prepare_browser(
+    app: tauri::AppHandle,
     state: State<'_, AppState>,
     user_data_dir: Option<String>,
     profile_directory: Option<String>,
@@ -53,6 +91,11 @@ pub async fn prepare_browser(
         }
     }
 
+    let selection = BrowserSelection {
+        browser_channel: browser_channel.clone(),
+        user_data_dir: user_data_dir.clone(),
+        profile_directory: profile_directory.clone(),
+    };
     let sidecar_dir = interaction::resolve_sidecar_dir();
     let options = interaction::PrepareBrowserOptions {
         user_data_dir,
@@ -68,19 +111,101 @@ pub async fn prepare_browser(
         channel: None,
         actions: Vec::new(),
         session: None,
+        last_action_at: None,
+        idle_warned: false,
+        screenshots: None,
     }));
+    let sidecar = Arc::new(sidecar);
 
     // Spawn a long-lived forwarding task that reads sidecar events.
-    // Only forwards to the frontend when a recording is active.
+    // Only forwards to the frontend when a recording is active, emits
+    // throttled `recording-progress` updates, and watches active takes for
+    // long stretches without any captured action.
     let fwd_recording = recording.clone();
+    let fwd_app = app.clone();
+    let fwd_sidecar = sidecar.clone();
     let fwd_handle = tokio::spawn(async move {
         let mut rx = event_rx;
-        while let Some(captured) = rx.recv().await {
-            let mut inner = fwd_recording.lock().await;
-            if inner.active {
-                inner.actions.push(captured.clone());
-                if let Some(ch) = &inner.channel {
-                    let _ = ch.send(captured);
+        let mut idle_check = tokio::time::interval(interaction::RECORDING_IDLE_CHECK_INTERVAL);
+        let mut last_progress: Option<Instant> = None;
+        let mut pending_progress: Option<RecordingProgress> = None;
+        loop {
+            let flush_at = last_progress.map_or_else(Instant::now, |at| {
+                at + interaction::RECORDING_PROGRESS_INTERVAL
+            });
+            tokio::select! {
+                captured = rx.recv() => {
+                    let Some(captured) = captured else { break };
+                    let mut inner = fwd_recording.lock().await;
+                    if inner.active {
+                        inner.last_action_at = Some(Instant::now());
+                        inner.idle_warned = false;
+                        if inner
+                            .actions
+                            .last()
+                            .is_some_and(|prev| interaction::should_dedup(prev, &captured))
+                        {
+                            continue;
+                        }
+                        let index = inner.actions.len();
+                        let progress = RecordingProgress {
+                            count: index + 1,
+                            last_action_type: automation::action_type(&captured.action),
+                        };
+                        inner.actions.push(captured.clone());
+                        let screenshots = inner.screenshots.clone();
+                        let session_id = inner.session.as_ref().map(|session| session.id);
+                        // The element capture waits on the browser; don't hold the
+                        // recording lock across it.
+                        drop(inner);
+                        let element_shot = match &screenshots {
+                            Some((root, shots)) => {
+                                element_screenshot(&fwd_sidecar, &captured, root, shots).await
+                            }
+                            None => None,
+                        };
+                        let mut inner = fwd_recording.lock().await;
+                        if inner.session.as_ref().map(|session| session.id) != session_id {
+                            // The take ended while the element was being captured.
+                            if let (Some(path), Some((root, _))) = (element_shot, &screenshots) {
+                                let _ = std::fs::remove_file(root.join(path));
+                            }
+                            continue;
+                        }
+                        let Some(stored) = inner.actions.get_mut(index) else {
+                            continue;
+                        };
+                        if let Some(path) = element_shot {
+                            if let Some(page_shot) = stored.metadata.captured_screenshot.replace(path)
+                            {
+                                let _ = std::fs::remove_file(page_shot);
+                            }
+                        }
+                        let captured = stored.clone();
+                        if let Some(ch) = &inner.channel {
+                            let _ = ch.send(captured);
+                        }
+                        drop(inner);
+                        if Instant::now() >= flush_at {
+                            emit_recording_progress(&fwd_app, progress);
+                            last_progress = Some(Instant::now());
+                            pending_progress = None;
+                        } else {
+                            pending_progress = Some(progress);
+                        }
+                    }
+                }
+                _ = tokio::time::sleep_until(flush_at.into()), if pending_progress.is_some() => {
+                    let active = fwd_recording.lock().await.active;
+                    if let Some(progress) = pending_progress.take().filter(|_| active) {
+                        emit_recording_progress(&fwd_app, progress);
+                        last_progress = Some(Instant::now());
+                    }
+                }
+                _ = idle_check.tick() => {
+                    if check_recording_idle(&fwd_app, &fwd_recording).await {
+                        auto_stop_idle_recording(&fwd_app).await;
+                    }
                 }
             }
         }
@@ -98,9 +223,132 @@ pub async fn prepare_browser(
         *browser = Some(connection);
     }
 
+    if let Err(e) = save_browser_selection(&app, &state, &selection) {
+        log::warn!("[interaction] could not remember browser selection: {e}");
+    }
+
     Ok(resolved_channel)
 }
 
+/// Capture a screenshot cropped to the element an action targets, returning
+/// its project-relative path.
+///
+/// Actions without selectors, and any action whose element capture fails, get
+/// `None` and keep the sidecar's page screenshot.
+async fn element_screenshot(
+    sidecar: &SidecarManager,
+    captured: &CapturedAction,
+    project_root: &std::path::Path,
+    screenshots_dir: &std::path::Path,
+) -> Option<std::path::PathBuf> {
+    let selector = captured.action.selectors()?.first()?;
+    match interaction::capture_element(sidecar, selector, project_root, screenshots_dir).await {
+        Ok(path) => Some(path),
+        Err(e) => {
+            log::warn!("[interaction] could not capture element screenshot: {e}");
+            None
+        }
+    }
+}
+
+/// Payload of the `recording-progress` event.
+#[derive(Debug, Clone, serde::Serialize)]
+struct RecordingProgress {
+    count: usize,
+    last_action_type: String,
+}
+
+fn emit_recording_progress(app: &tauri::AppHandle, progress: RecordingProgress) {
+    use tauri::Emitter;
+
+    if let Err(e) = app.emit("recording-progress", progress) {
+        log::warn!("[interaction] could not emit recording-progress: {e}");
+    }
+}
+
+/// Payload of the `recording-idle` event.
+#[derive(Debug, Clone, serde::Serialize)]
+struct RecordingIdle {
+    idle_seconds: u64,
+    auto_stop_in_seconds: Option<u64>,
+}
+
+/// Run one idle watchdog step. Emits `recording-idle` the first time a take
+/// crosses the idle threshold and returns whether it should now be stopped.
+async fn check_recording_idle(
+    app: &tauri::AppHandle,
+    recording: &tokio::sync::Mutex<RecordingInner>,
+) -> bool {
+    use tauri::Emitter;
+
+    let mut inner = recording.lock().await;
+    let Some(last_action_at) = inner.last_action_at.filter(|_| inner.active) else {
+        return false;
+    };
+    let idle_for = last_action_at.elapsed();
+    match interaction::idle_action(idle_for, inner.idle_warned) {
+        interaction::IdleAction::None => false,
+        interaction::IdleAction::Warn => {
+            inner.idle_warned = true;
+            let payload = RecordingIdle {
+                idle_seconds: idle_for.as_secs(),
+                auto_stop_in_seconds: interaction::RECORDING_IDLE_AUTO_STOP_GRACE
+                    .map(|grace| grace.as_secs()),
+            };
+            if let Err(e) = app.emit("recording-idle", payload) {
+                log::warn!("[interaction] could not emit recording-idle: {e}");
+            }
+            false
+        }
+        interaction::IdleAction::Stop => true,
+    }
+}
+
+/// Stop a take the idle watchdog gave up on and hand the saved session to the
+/// frontend via `recording-auto-stopped`.
+async fn auto_stop_idle_recording(app: &tauri::AppHandle) {
+    use tauri::{Emitter, Manager};
+
+    let state = app.state::<AppState>();
+    match stop_active_recording(&state).await {
+        Ok(session) => {
+            log::info!("[interaction] stopped idle recording {}", session.id);
+            if let Err(e) = app.emit("recording-auto-stopped", session) {
+                log::warn!("[interaction] could not emit recording-auto-stopped: {e}");
+            }
+        }
+        Err(e) => log::warn!("[interaction] could not stop idle recording: {e}"),
+    }
+}
+
+fn save_browser_selection(
+    app: &tauri::AppHandle,
+    state: &AppState,
+    selection: &BrowserSelection,
+) -> Result<(), String> {
+    let root = project_root(state)?;
+    let store = app.store(UI_STORE_FILE).map_err(|e| e.to_string())?;
+    store.set(
+        browser_selection_key(&root),
+        serde_json::to_value(selection).map_err(|e| e.to_string())?,
+    );
+    store.save().map_err(|e| e.to_string())
+}
+
+/// The browser options last used to prepare a browser for the current
+/// project, so the recorder dialog can be pre-filled after a restart.
+#[tauri::command]
+pub async fn get_last_browser_selection(
+    app: tauri::AppHandle,
+    state: State<'_, AppState>,
+) -> Result<Option<BrowserSelection>, String> {
+    let root = project_root(&state)?;
+    let store = app.store(UI_STORE_FILE).map_err(|e| e.to_string())?;
+    Ok(store
+        .get(browser_selection_key(&root))
+        .and_then(|value| serde_json::from_value(value).ok()))
+}
+
 /// Disconnect and close the recording browser.
 #[tauri::command]
 pub async fn disconnect_browser(state: State<'_, AppState>) -> Result<(), String> {
@@ -175,6 +423,9 @@ pub async fn start_recording_session(
         inner.channel = Some(on_action);
         inner.actions.clear();
         inner.session = Some(session);
+        inner.last_action_at = Some(Instant::now());
+        inner.idle_warned = false;
+        inner.screenshots = Some((project_root, screenshots_dir));
     }
 
     Ok(session_id)
@@ -185,6 +436,10 @@ pub async fn start_recording_session(
 /// Saves the session to disk and returns it.
 #[tauri::command]
 pub async fn stop_recording_session(state: State<'_, AppState>) -> Result<RecordedSession, String> {
+    stop_active_recording(&state).await
+}
+
+async fn stop_active_recording(state: &AppState) -> Result<RecordedSession, String> {
     // Scope: hold browser lock, extract session, release lock
     let session = {
         let browser_guard = state.browser.lock().await;
@@ -199,6 +454,8 @@ pub async fn stop_recording_session(state: State<'_, AppState>) -> Result<Record
         let mut inner = browser.recording.lock().await;
         inner.active = false;
         inner.channel = None;
+        inner.last_action_at = None;
+        inner.screenshots = None;
 
         let actions = std::mem::take(&mut inner.actions);
         let mut session = inner.session.take().ok_or("No recording session")?;
@@ -231,3 +488,198 @@ pub async fn get_session_actions(
     let inner = browser.recording.lock().await;
     Ok(inner.actions.clone())
 }
+
+/// Add an author note to the active recording at the current point in the take.
+///
+/// The note is stored as an `Annotation` action and forwarded to the frontend
+/// like any captured action.
+#[tauri::command]
+pub async fn add_annotation_to_recording(
+    state: State<'_, AppState>,
+    text: String,
+) -> Result<CapturedAction, String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err("Annotation text is empty".to_string());
+    }
+
+    let browser_guard = state.browser.lock().await;
+    let browser = browser_guard.as_ref().ok_or("No browser prepared")?;
+    let mut inner = browser.recording.lock().await;
+    if !inner.active {
+        return Err("Not recording".to_string());
+    }
+
+    let annotation = CapturedAction {
+        action: Action::Annotation {
+            text: text.to_string(),
+        },
+        metadata: ActionMetadata {
+            captured_screenshot: None,
+            selector_strategies: Vec::new(),
+            timestamp_ms: Utc::now().timestamp_millis().max(0) as u64,
+            confidence: 1.0,
+            context_snapshot: None,
+        },
+        raw_event: None,
+    };
+    inner.actions.push(annotation.clone());
+    inner.last_action_at = Some(Instant::now());
+    inner.idle_warned = false;
+    if let Some(ch) = &inner.channel {
+        let _ = ch.send(annotation.clone());
+    }
+    Ok(annotation)
+}
+
+/// Check an action's selectors against the live page in the prepared browser.
+///
+/// Returns the first strategy that matches exactly one element, or `None`
+/// when every selector is missing or ambiguous so the UI can flag the action.
+#[tauri::command]
+pub async fn validate_selector(
+    state: State<'_, AppState>,
+    selectors: Vec<SelectorStrategy>,
+) -> Result<Option<interaction::ResolvedSelector>, String> {
+    let browser_guard = state.browser.lock().await;
+    let browser = browser_guard.as_ref().ok_or("No browser prepared")?;
+    interaction::first_unique_selector(&*browser.sidecar, &selectors)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// List the project's saved recording sessions, newest first.
+#[tauri::command]
+pub async fn list_sessions(state: State<'_, AppState>) -> Result<Vec<SessionSummary>, String> {
+    let root = project_root(&state)?;
+    interaction::list_sessions(&root).map_err(|e| e.to_string())
+}
+
+/// Load a saved recording session with all of its actions.
+#[tauri::command]
+pub async fn get_session(
+    state: State<'_, AppState>,
+    session_id: String,
+) -> Result<RecordedSession, String> {
+    let root = project_root(&state)?;
+    interaction::load_session(&root, &session_id).map_err(|e| e.to_string())
+}
+
+/// Link a saved recording session to a sketch and rewrite the session file.
+#[tauri::command]
+pub async fn link_session_to_sketch(
+    state: State<'_, AppState>,
+    session_id: String,
+    sketch_path: String,
+) -> Result<RecordedSession, String> {
+    let root = project_root(&state)?;
+    let abs_path = project::safe_resolve(&root, &sketch_path).map_err(|e| e.to_string())?;
+    if !abs_path.is_file() {
+        return Err(format!("Sketch not found: {sketch_path}"));
+    }
+    interaction::link_session_to_sketch(&root, &session_id, &sketch_path).map_err(|e| e.to_string())
+}
+
+/// List the saved recording sessions linked to a sketch, newest first.
+#[tauri::command]
+pub async fn sessions_for_sketch(
+    state: State<'_, AppState>,
+    sketch_path: String,
+) -> Result<Vec<SessionSummary>, String> {
+    let root = project_root(&state)?;
+    interaction::sessions_for_sketch(&root, &sketch_path).map_err(|e| e.to_string())
+}
+
+/// Delete saved sessions beyond the most recent `keep_latest`, keeping any
+/// linked to a sketch. Returns how many were removed.
+#[tauri::command]
+pub async fn prune_sessions(
+    state: State<'_, AppState>,
+    keep_latest: usize,
+) -> Result<usize, String> {
+    let root = project_root(&state)?;
+    interaction::prune_sessions(&root, keep_latest).map_err(|e| e.to_string())
+}
+
+/// Drop actions the recorder captured with low confidence from a saved session.
+///
+/// `threshold` defaults to 0.3; actions at exactly the threshold are kept.
+/// Rewrites the session file and returns how many actions were removed.
+#[tauri::command]
+pub async fn filter_session_confidence(
+    state: State<'_, AppState>,
+    session_id: String,
+    threshold: Option<f32>,
+) -> Result<usize, String> {
+    let root = project_root(&state)?;
+    let session = interaction::load_session(&root, &session_id).map_err(|e| e.to_string())?;
+    let threshold = threshold.unwrap_or(cleanup::DEFAULT_CONFIDENCE_THRESHOLD);
+    let (filtered, removed) = cleanup::filter_low_confidence(&session, threshold);
+    if removed > 0 {
+        interaction::save_session(&filtered, &root, "").map_err(|e| e.to_string())?;
+    }
+    Ok(removed)
+}
+
+/// Put each action's most stable selector first and drop low-confidence
+/// actions in a saved session.
+///
+/// `threshold` defaults to 0.3. Rewrites the session file when anything
+/// changed and emits `session-changed` with the session id.
+#[tauri::command]
+pub async fn stabilize_session(
+    app: tauri::AppHandle,
+    state: State<'_, AppState>,
+    session_id: String,
+    threshold: Option<f32>,
+) -> Result<interaction::SessionStabilization, String> {
+    use tauri::Emitter;
+
+    let root = project_root(&state)?;
+    let threshold = threshold.unwrap_or(cleanup::DEFAULT_CONFIDENCE_THRESHOLD);
+    let outcome =
+        interaction::stabilize_session(&root, &session_id, threshold).map_err(|e| e.to_string())?;
+    if outcome.reordered > 0 || outcome.dropped > 0 {
+        if let Err(e) = app.emit("session-changed", &session_id) {
+            log::warn!("[interaction] could not emit session-changed: {e}");
+        }
+    }
+    Ok(outcome)
+}
+
+/// Merge two saved takes into one session.
+///
+/// The second take is appended to the first; the merged session keeps the
+/// first take's id and overwrites its file. The second take's file is left as-is.
+#[tauri::command]
+pub async fn merge_sessions(
+    state: State<'_, AppState>,
+    first_id: String,
+    second_id: String,
+) -> Result<RecordedSession, String> {
+    let root = project_root(&state)?;
+    let first = interaction::load_session(&root, &first_id).map_err(|e| e.to_string())?;
+    let second = interaction::load_session(&root, &second_id).map_err(|e| e.to_string())?;
+    let merged = interaction::merge_sessions(&first, &second);
+    interaction::save_session(&merged, &root, "").map_err(|e| e.to_string())?;
+    Ok(merged)
+}
+
+/// Trim a saved session to the actions in `[start_index, end_index)`.
+///
+/// Timestamps are rebased to start at 0. Rewrites the session file and
+/// returns the trimmed session.
+#[tauri::command]
+pub async fn trim_session(
+    state: State<'_, AppState>,
+    session_id: String,
+    start_index: usize,
+    end_index: usize,
+) -> Result<RecordedSession, String> {
+    let root = project_root(&state)?;
+    let session = interaction::load_session(&root, &session_id).map_err(|e| e.to_string())?;
+    let trimmed =
+        interaction::trim_session(&session, start_index, end_index).map_err(|e| e.to_string())?;
+    interaction::save_session(&trimmed, &root, "").map_err(|e| e.to_string())?;
+    Ok(trimmed)
+}
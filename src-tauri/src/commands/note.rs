@@ -2,12 +2,16 @@
 //!
 //! Notes are `.md` files in the project folder, identified by relative path.
 
-use tauri::State;
+use std::path::Path;
+
+use draftline::tauri_contract as contract;
+use tauri::{AppHandle, Runtime, State};
 use tauri_plugin_auditaur::auditaur_command;
 
 use crate::engine::project;
 use crate::models::script::ProjectView;
 use crate::models::sketch::NoteSummary;
+use crate::util::document_events::emit_document_changed;
 use crate::AppState;
 
 /// Helper: get the project root from current state.
@@ -21,8 +25,22 @@ fn document_root_from_project_view(view: &ProjectView) -> std::path::PathBuf {
     view.root.clone()
 }
 
+/// Whether the open workspace opted into writing notes with LF line endings.
+pub(crate) fn normalize_line_endings(state: &AppState) -> bool {
+    let repo_root = state
+        .current_repo
+        .lock()
+        .ok()
+        .and_then(|repo| repo.as_ref().map(|view| view.root.clone()));
+    repo_root.is_some_and(|root| project::repo_normalizes_line_endings(&root))
+}
+
 #[tauri::command]
-pub async fn create_note(relative_path: String, state: State<'_, AppState>) -> Result<(), String> {
+pub async fn create_note(
+    relative_path: String,
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
     let root = project_root(&state)?;
     let abs_path = project::safe_resolve(&root, &relative_path).map_err(|e| e.to_string())?;
 
@@ -30,7 +48,9 @@ pub async fn create_note(relative_path: String, state: State<'_, AppState>) -> R
         return Err(format!("File already exists: {relative_path}"));
     }
 
-    project::write_note(&abs_path, "").map_err(|e| e.to_string())?;
+    project::write_note(&abs_path, "", normalize_line_endings(&state))
+        .map_err(|e| e.to_string())?;
+    emit_document_changed(&app, "note", &relative_path);
     Ok(())
 }
 
@@ -42,6 +62,18 @@ pub async fn get_note(relative_path: String, state: State<'_, AppState>) -> Resu
     project::read_note(&abs_path).map_err(|e| e.to_string())
 }
 
+/// Hash of the note on disk, to pass back as `update_note`'s `base_hash`.
+#[auditaur_command(skip_all, err)]
+pub async fn get_note_hash(
+    relative_path: String,
+    state: State<'_, AppState>,
+) -> Result<String, String> {
+    let root = project_root(&state)?;
+    let abs_path = project::safe_resolve(&root, &relative_path).map_err(|e| e.to_string())?;
+
+    project::note_file_hash(&abs_path).map_err(|e| e.to_string())
+}
+
 #[auditaur_command(skip_all, err)]
 pub async fn get_note_lock(
     relative_path: String,
@@ -65,20 +97,73 @@ pub async fn set_note_lock(
     project::set_note_lock(&root, &relative_path, locked).map_err(|e| e.to_string())
 }
 
-#[auditaur_command(skip_all, err)]
+#[auditaur_command(skip_all)]
 pub async fn update_note(
     relative_path: String,
     content: String,
+    base_hash: Option<String>,
+    app: AppHandle,
     state: State<'_, AppState>,
-) -> Result<(), String> {
-    let root = project_root(&state)?;
-    let abs_path = project::safe_resolve(&root, &relative_path).map_err(|e| e.to_string())?;
-    project::ensure_note_unlocked(&root, &relative_path).map_err(|e| e.to_string())?;
+) -> Result<(), contract::TauriCommandError> {
+    let root = project_root(&state).map_err(|message| contract::TauriCommandError {
+        code: "no_project".into(),
+        message,
+        details: None,
+    })?;
+    let normalize_eol = normalize_line_endings(&state);
+    save_note_and_notify(
+        &app,
+        &root,
+        &relative_path,
+        &content,
+        base_hash.as_deref(),
+        normalize_eol,
+    )
+}
 
-    project::write_note(&abs_path, &content).map_err(|e| e.to_string())?;
+/// Write a note after checking its lock and base hash, then tell open editors.
+fn save_note_and_notify<R: Runtime>(
+    app: &AppHandle<R>,
+    root: &Path,
+    relative_path: &str,
+    content: &str,
+    base_hash: Option<&str>,
+    normalize_eol: bool,
+) -> Result<(), contract::TauriCommandError> {
+    let abs_path = project::safe_resolve(root, relative_path).map_err(note_error)?;
+    project::ensure_note_unlocked(root, relative_path).map_err(note_error)?;
+    project::write_note_checked(&abs_path, content, base_hash, normalize_eol)
+        .map_err(note_error)?;
+    emit_document_changed(app, "note", relative_path);
     Ok(())
 }
 
+/// A conflict carries the content now on disk and its hash, so the editor can
+/// offer a merge and retry against the new base.
+fn note_error(error: project::ProjectError) -> contract::TauriCommandError {
+    let message = error.to_string();
+    match error {
+        project::ProjectError::NoteConflict { current_content } => contract::TauriCommandError {
+            code: "note_conflict".into(),
+            message,
+            details: Some(serde_json::json!({
+                "current_hash": project::hash_note(&current_content),
+                "current_content": current_content,
+            })),
+        },
+        project::ProjectError::Locked(_) => contract::TauriCommandError {
+            code: "note_locked".into(),
+            message,
+            details: None,
+        },
+        _ => contract::TauriCommandError {
+            code: "project_error".into(),
+            message,
+            details: None,
+        },
+    }
+}
+
 #[tauri::command]
 pub async fn delete_note(relative_path: String, state: State<'_, AppState>) -> Result<(), String> {
     let root = project_root(&state)?;
@@ -295,4 +380,74 @@ mod tests {
         );
         assert_ne!(document_root_from_project_view(&view), view.repo_root);
     }
+
+    #[test]
+    fn saving_a_note_emits_document_changed() {
+        use crate::util::document_events::DOCUMENT_CHANGED_EVENT;
+        use tauri::Listener;
+
+        let tmp = tempfile::TempDir::new().unwrap();
+        let app = tauri::test::mock_app();
+        let (tx, rx) = std::sync::mpsc::channel();
+        app.listen(DOCUMENT_CHANGED_EVENT, move |event| {
+            let _ = tx.send(event.payload().to_string());
+        });
+
+        save_note_and_notify(
+            app.handle(),
+            tmp.path(),
+            "notes/plan.md",
+            "# Plan\n",
+            None,
+            false,
+        )
+        .unwrap();
+
+        let payload: serde_json::Value =
+            serde_json::from_str(&rx.recv_timeout(std::time::Duration::from_secs(1)).unwrap())
+                .unwrap();
+        assert_eq!(payload["kind"], "note");
+        assert_eq!(payload["path"], "notes/plan.md");
+        assert_eq!(
+            std::fs::read_to_string(tmp.path().join("notes/plan.md")).unwrap(),
+            "# Plan\n"
+        );
+    }
+
+    #[test]
+    fn stale_base_hash_is_reported_as_a_structured_conflict() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let app = tauri::test::mock_app();
+        let note = tmp.path().join("plan.md");
+        std::fs::write(&note, "edited elsewhere\n").unwrap();
+        let stale = project::hash_note("original\n");
+
+        let error = save_note_and_notify(
+            app.handle(),
+            tmp.path(),
+            "plan.md",
+            "mine\n",
+            Some(&stale),
+            false,
+        )
+        .unwrap_err();
+
+        assert_eq!(error.code, "note_conflict");
+        let details = error.details.unwrap();
+        assert_eq!(details["current_content"], "edited elsewhere\n");
+        assert_eq!(
+            details["current_hash"],
+            project::note_file_hash(&note).unwrap()
+        );
+        save_note_and_notify(
+            app.handle(),
+            tmp.path(),
+            "plan.md",
+            "mine\n",
+            details["current_hash"].as_str(),
+            false,
+        )
+        .unwrap();
+        assert_eq!(std::fs::read_to_string(&note).unwrap(), "mine\n");
+    }
 }
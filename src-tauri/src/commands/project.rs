@@ -8,13 +8,15 @@ use tauri::State;
 use tauri_plugin_auditaur::auditaur_command;
 use tauri_plugin_store::StoreExt;
 
+use crate::engine::agent::llm::{LlmConfig, ProjectLlmConfigView};
 use crate::engine::{
     agent_state::AgentStateStore, draftline_adapter::CutReadyDraftlineAdapter, project,
 };
 use crate::models::script::{ProjectEntry, ProjectView, RecentProject, RepoView};
-use crate::AppState;
+use crate::{AppState, ProjectLock};
 
 const STORE_FILE: &str = "recent-projects.json";
+const MAX_RECENT_PROJECTS: usize = 20;
 const STARTUP_PROJECT_ENV: &str = "CUTREADY_PROJECT";
 
 /// Helper: get the project root from current state.
@@ -82,26 +84,63 @@ pub async fn create_project_folder(
     app: tauri::AppHandle,
     state: State<'_, AppState>,
 ) -> Result<ProjectView, String> {
-    let root = PathBuf::from(&path);
-    let view = project::init_project_folder(&root).map_err(|e| e.to_string())?;
+    let view = project::init_project_folder(&PathBuf::from(&path)).map_err(|e| e.to_string())?;
+    activate_new_project(&app, &state, &path, view)
+}
+
+/// Initialize a new project seeded with starter content.
+#[tauri::command]
+pub async fn create_project_from_template(
+    path: String,
+    template: project::ProjectTemplate,
+    app: tauri::AppHandle,
+    state: State<'_, AppState>,
+) -> Result<ProjectView, String> {
+    let view = project::create_from_template(&PathBuf::from(&path), template)
+        .map_err(|e| e.to_string())?;
+    activate_new_project(&app, &state, &path, view)
+}
 
+/// Make a freshly created project current and remember it.
+fn activate_new_project(
+    app: &tauri::AppHandle,
+    state: &AppState,
+    path: &str,
+    view: ProjectView,
+) -> Result<ProjectView, String> {
     // Set repo view (new project = repo root is project root)
     {
         let mut repo_lock = state.current_repo.lock().map_err(|e| e.to_string())?;
-        *repo_lock = Some(RepoView::new(root.clone()));
+        *repo_lock = Some(RepoView::new(PathBuf::from(path)));
     }
     {
         let mut current = state.current_project.lock().map_err(|e| e.to_string())?;
         *current = Some(view.clone());
     }
-    reconcile_abandoned_agent_runs(&state, &view);
+    reconcile_abandoned_agent_runs(state, &view);
 
     // Auto-add to recent projects
-    let _ = add_to_recent_projects(&app, &path, None);
+    let _ = add_to_recent_projects(app, path, None);
 
     Ok(view)
 }
 
+/// Adopt an existing git repository as a project without rewriting its history.
+///
+/// The repository is adopted through [`project::adopt_repo`] under the project
+/// lock, then the folder opens like any other project.
+#[tauri::command]
+pub async fn adopt_git_repo(
+    path: String,
+    app: tauri::AppHandle,
+    state: State<'_, AppState>,
+    lock: State<'_, ProjectLock>,
+) -> Result<ProjectView, String> {
+    let root = PathBuf::from(&path);
+    crate::commands::draftline::adopt_workspace_at(&root, app.clone(), &lock).await?;
+    open_folder(&app, &state, &path)
+}
+
 /// Open an existing project folder.
 /// In multi-project repos, restores the last-used project (or falls back to the first).
 /// In single-project repos, behaves exactly as before (repo root = project root).
@@ -111,7 +150,16 @@ pub async fn open_project_folder(
     app: tauri::AppHandle,
     state: State<'_, AppState>,
 ) -> Result<ProjectView, String> {
-    let root = PathBuf::from(&path);
+    open_folder(&app, &state, &path)
+}
+
+/// Make the repo at `path` and its last-used project current.
+fn open_folder(
+    app: &tauri::AppHandle,
+    state: &AppState,
+    path: &str,
+) -> Result<ProjectView, String> {
+    let root = PathBuf::from(path);
 
     // Always set repo view
     let (repo, projects) = project::open_repo(&root).map_err(|e| e.to_string())?;
@@ -121,7 +169,7 @@ pub async fn open_project_folder(
     }
 
     // Activate the last-used project, falling back to the first project
-    let last_active = get_last_active_for_repo(&app, &path);
+    let last_active = get_last_active_for_repo(app, path);
     let entry = last_active
         .as_ref()
         .and_then(|lp| projects.iter().find(|p| p.path == *lp))
@@ -138,7 +186,7 @@ pub async fn open_project_folder(
         let mut current = state.current_project.lock().map_err(|e| e.to_string())?;
         *current = Some(view.clone());
     }
-    reconcile_abandoned_agent_runs(&state, &view);
+    reconcile_abandoned_agent_runs(state, &view);
     if let Err(err) = project::migrate_legacy_chat_sessions(&view.repo_root, &view.root) {
         log::warn!("[project] could not archive legacy chat sessions: {err}");
     }
@@ -149,7 +197,7 @@ pub async fn open_project_folder(
     } else {
         None
     };
-    let _ = add_to_recent_projects(&app, &path, active_project);
+    let _ = add_to_recent_projects(app, path, active_project);
 
     Ok(view)
 }
@@ -182,14 +230,121 @@ pub async fn close_project(state: State<'_, AppState>) -> Result<(), String> {
 pub async fn get_recent_projects(app: tauri::AppHandle) -> Result<Vec<RecentProject>, String> {
     let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
 
-    let recent: Vec<RecentProject> = store
+    let mut recent: Vec<RecentProject> = store
         .get("recent_projects")
         .and_then(|v| serde_json::from_value(v).ok())
         .unwrap_or_default();
+    mark_recent_project_existence(&mut recent);
+    order_recent_projects(&mut recent);
+
+    Ok(recent)
+}
+
+/// Pin or unpin a recent project and return the reordered list.
+#[auditaur_command(skip_all, err)]
+pub async fn toggle_pin_recent_project(
+    path: String,
+    app: tauri::AppHandle,
+) -> Result<Vec<RecentProject>, String> {
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+
+    let mut recent: Vec<RecentProject> = store
+        .get("recent_projects")
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default();
+    toggle_recent_project_pin(&mut recent, &path)?;
+    mark_recent_project_existence(&mut recent);
+
+    store.set(
+        "recent_projects",
+        serde_json::to_value(&recent).unwrap_or_default(),
+    );
+    store.save().map_err(|e| e.to_string())?;
+
+    Ok(recent)
+}
+
+/// Drop recent projects whose folders no longer exist and return the rest.
+#[auditaur_command(skip_all, err)]
+pub async fn prune_recent_projects(app: tauri::AppHandle) -> Result<Vec<RecentProject>, String> {
+    let store = app.store(STORE_FILE).map_err(|e| e.to_string())?;
+
+    let mut recent: Vec<RecentProject> = store
+        .get("recent_projects")
+        .and_then(|v| serde_json::from_value(v).ok())
+        .unwrap_or_default();
+    mark_recent_project_existence(&mut recent);
+    recent.retain(|r| r.exists);
+    order_recent_projects(&mut recent);
+
+    store.set(
+        "recent_projects",
+        serde_json::to_value(&recent).unwrap_or_default(),
+    );
+    store.save().map_err(|e| e.to_string())?;
 
     Ok(recent)
 }
 
+fn mark_recent_project_existence(recent: &mut [RecentProject]) {
+    for entry in recent {
+        entry.exists = Path::new(&entry.path).exists();
+    }
+}
+
+/// Put pinned projects first, in the order they were pinned, then the rest
+/// by most recently opened. Caps the list without ever dropping a pin.
+fn order_recent_projects(recent: &mut Vec<RecentProject>) {
+    let (mut ordered, mut unpinned): (Vec<_>, Vec<_>) = recent.drain(..).partition(|r| r.pinned);
+    unpinned.sort_by(|a, b| b.last_opened.cmp(&a.last_opened));
+    let keep = MAX_RECENT_PROJECTS.saturating_sub(ordered.len());
+    ordered.extend(unpinned.into_iter().take(keep));
+    *recent = ordered;
+}
+
+/// Note that `path` was just opened. An existing entry is updated where it
+/// stands, so a pinned project keeps its place among the pins.
+fn record_recent_project(
+    recent: &mut Vec<RecentProject>,
+    path: &str,
+    last_active_project: Option<String>,
+) {
+    let now = Utc::now();
+    match recent.iter_mut().find(|r| r.path == path) {
+        Some(entry) => {
+            entry.last_opened = now;
+            entry.last_active_project = last_active_project;
+            entry.exists = true;
+        }
+        None => recent.push(RecentProject {
+            path: path.to_string(),
+            last_opened: now,
+            last_active_project,
+            exists: true,
+            pinned: false,
+        }),
+    }
+    order_recent_projects(recent);
+}
+
+/// Flip the pin on `path`. A newly pinned entry goes after existing pins.
+fn toggle_recent_project_pin(recent: &mut Vec<RecentProject>, path: &str) -> Result<(), String> {
+    let index = recent
+        .iter()
+        .position(|r| r.path == path)
+        .ok_or_else(|| format!("{path} is not in the recent projects list"))?;
+    let mut entry = recent.remove(index);
+    entry.pinned = !entry.pinned;
+    if entry.pinned {
+        let after_pins = recent.iter().take_while(|r| r.pinned).count();
+        recent.insert(after_pins, entry);
+    } else {
+        recent.push(entry);
+    }
+    order_recent_projects(recent);
+    Ok(())
+}
+
 /// Add a project to the recent projects list.
 #[tauri::command]
 pub async fn add_recent_project(path: String, app: tauri::AppHandle) -> Result<(), String> {
@@ -242,21 +397,7 @@ fn add_to_recent_projects(
         .and_then(|v| serde_json::from_value(v).ok())
         .unwrap_or_default();
 
-    // Remove existing entry for same path
-    recent.retain(|r| r.path != path);
-
-    // Add at front
-    recent.insert(
-        0,
-        RecentProject {
-            path: path.to_string(),
-            last_opened: Utc::now(),
-            last_active_project,
-        },
-    );
-
-    // Keep at most 20 recent projects
-    recent.truncate(20);
+    record_recent_project(&mut recent, path, last_active_project);
 
     store.set(
         "recent_projects",
@@ -332,6 +473,21 @@ pub async fn set_sidebar_order(
     project::write_sidebar_order(&rp_root, &proj_root, &order).map_err(|e| e.to_string())
 }
 
+/// Move a single sidebar entry to `to_index` within its section and return
+/// the updated order. Reading and writing here avoids racing whole-order saves.
+#[tauri::command]
+pub async fn move_sidebar_item(
+    id: String,
+    to_index: usize,
+    state: State<'_, AppState>,
+) -> Result<project::SidebarOrder, String> {
+    let (proj_root, rp_root) = project_and_repo_root(&state)?;
+    let mut order = project::read_sidebar_order(&rp_root, &proj_root);
+    project::move_sidebar_item(&mut order, &id, to_index).map_err(|e| e.to_string())?;
+    project::write_sidebar_order(&rp_root, &proj_root, &order).map_err(|e| e.to_string())?;
+    Ok(order)
+}
+
 /// Get workspace state (open tabs, active tab, chat session) for the current project.
 #[auditaur_command(skip_all, err)]
 pub async fn get_workspace_state(
@@ -351,6 +507,43 @@ pub async fn set_workspace_state(
     project::write_workspace_state(&rp_root, &proj_root, &workspace).map_err(|e| e.to_string())
 }
 
+/// Get the LLM configuration pinned to the current project, with the API key
+/// redacted.
+#[auditaur_command(skip_all, err)]
+pub async fn get_project_llm_config(
+    state: State<'_, AppState>,
+) -> Result<Option<ProjectLlmConfigView>, String> {
+    let (proj_root, rp_root) = project_and_repo_root(&state)?;
+    Ok(project::read_llm_config(&rp_root, &proj_root).map(|config| (&config).into()))
+}
+
+/// Pin an LLM configuration to the current project, or clear it with `None`.
+/// An empty API key keeps the key already stored for the project.
+#[auditaur_command(skip_all, err)]
+pub async fn set_project_llm_config(
+    config: Option<LlmConfig>,
+    state: State<'_, AppState>,
+) -> Result<Option<ProjectLlmConfigView>, String> {
+    let (proj_root, rp_root) = project_and_repo_root(&state)?;
+    let config = config.map(|mut config| {
+        if config.api_key.trim().is_empty() {
+            if let Some(existing) = project::read_llm_config(&rp_root, &proj_root) {
+                config.api_key = existing.api_key;
+            }
+        }
+        config
+    });
+    project::write_llm_config(&rp_root, &proj_root, config.as_ref()).map_err(|e| e.to_string())?;
+    Ok(config.as_ref().map(ProjectLlmConfigView::from))
+}
+
+/// Document counts and storage usage for the current project.
+#[auditaur_command(skip_all, err)]
+pub async fn project_stats(state: State<'_, AppState>) -> Result<project::ProjectStats, String> {
+    let (proj_root, rp_root) = project_and_repo_root(&state)?;
+    project::project_stats(&rp_root, &proj_root).map_err(|e| e.to_string())
+}
+
 /// List all files and directories in the project folder.
 #[tauri::command]
 pub async fn list_all_files(state: State<'_, AppState>) -> Result<Vec<project::FileEntry>, String> {
@@ -568,7 +761,11 @@ fn sqlite_value_to_preview(value: ValueRef<'_>) -> DatabaseCellPreview {
 mod tests {
     use std::ffi::OsString;
 
-    use super::startup_project_path_from_args;
+    use super::{
+        mark_recent_project_existence, order_recent_projects, record_recent_project,
+        startup_project_path_from_args, toggle_recent_project_pin, MAX_RECENT_PROJECTS,
+    };
+    use crate::models::script::RecentProject;
 
     #[test]
     fn startup_project_path_parses_space_separated_flag() {
@@ -590,6 +787,91 @@ mod tests {
 
         assert_eq!(path.as_deref(), Some("D:\\cutready\\build-2026"));
     }
+
+    #[test]
+    fn recent_projects_flag_missing_folders() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let entry = |path: &std::path::Path| RecentProject {
+            path: path.to_string_lossy().into_owned(),
+            last_opened: chrono::Utc::now(),
+            last_active_project: None,
+            exists: false,
+            pinned: false,
+        };
+        let mut recent = vec![entry(tmp.path()), entry(&tmp.path().join("moved-away"))];
+
+        mark_recent_project_existence(&mut recent);
+
+        assert!(recent[0].exists);
+        assert!(!recent[1].exists);
+    }
+
+    #[test]
+    fn pinned_recent_projects_sort_first_and_survive_the_cap() {
+        let start = chrono::Utc::now();
+        let mut recent: Vec<RecentProject> = (0..MAX_RECENT_PROJECTS as i64 + 5)
+            .map(|i| RecentProject {
+                path: format!("/p{i}"),
+                last_opened: start - chrono::Duration::minutes(i),
+                last_active_project: None,
+                exists: true,
+                pinned: false,
+            })
+            .collect();
+
+        toggle_recent_project_pin(&mut recent, "/p24").unwrap();
+        toggle_recent_project_pin(&mut recent, "/p3").unwrap();
+
+        assert_eq!(recent.len(), MAX_RECENT_PROJECTS);
+        assert_eq!(recent[0].path, "/p24");
+        assert_eq!(recent[1].path, "/p3");
+        assert_eq!(recent[2].path, "/p0");
+
+        toggle_recent_project_pin(&mut recent, "/p24").unwrap();
+        assert_eq!(recent[0].path, "/p3");
+        assert_eq!(recent.last().unwrap().path, "/p24");
+
+        recent.iter_mut().for_each(|r| r.pinned = true);
+        recent.push(RecentProject {
+            path: "/new".into(),
+            last_opened: start,
+            last_active_project: None,
+            exists: true,
+            pinned: false,
+        });
+        order_recent_projects(&mut recent);
+        assert_eq!(recent.len(), MAX_RECENT_PROJECTS);
+        assert!(recent.iter().all(|r| r.pinned));
+        assert!(toggle_recent_project_pin(&mut recent, "/missing").is_err());
+    }
+
+    #[test]
+    fn reopening_a_pinned_project_keeps_the_pin_order() {
+        let start = chrono::Utc::now() - chrono::Duration::hours(1);
+        let mut recent: Vec<RecentProject> = (0..4)
+            .map(|i| RecentProject {
+                path: format!("/p{i}"),
+                last_opened: start - chrono::Duration::minutes(i),
+                last_active_project: None,
+                exists: true,
+                pinned: false,
+            })
+            .collect();
+        toggle_recent_project_pin(&mut recent, "/p2").unwrap();
+        toggle_recent_project_pin(&mut recent, "/p3").unwrap();
+
+        record_recent_project(&mut recent, "/p3", Some("demos/tour".into()));
+
+        let paths: Vec<_> = recent.iter().map(|r| r.path.as_str()).collect();
+        assert_eq!(paths, ["/p2", "/p3", "/p0", "/p1"]);
+        assert!(recent[1].last_opened > start);
+        assert_eq!(recent[1].last_active_project.as_deref(), Some("demos/tour"));
+
+        record_recent_project(&mut recent, "/p1", None);
+        record_recent_project(&mut recent, "/new", None);
+        let paths: Vec<_> = recent.iter().map(|r| r.path.as_str()).collect();
+        assert_eq!(paths, ["/p2", "/p3", "/new", "/p1", "/p0"]);
+    }
 }
 
 fn quote_sqlite_identifier(identifier: &str) -> String {
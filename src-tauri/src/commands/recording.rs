@@ -100,6 +100,20 @@ pub async fn discover_camera_formats(
     recording::discover_camera_formats(&camera_device_id).map_err(|e| e.to_string())
 }
 
+/// Probe a take or import media file for its duration and per-stream track
+/// info and store the result with that file's metadata.
+#[tauri::command]
+pub async fn probe_recording(
+    relative_path: String,
+    state: State<'_, AppState>,
+) -> Result<recording::RecordingProbe, String> {
+    let root = project_root(&state)?;
+    tokio::task::spawn_blocking(move || recording::probe_recording_asset(&root, &relative_path))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub async fn get_recording_prompter_script(
     scope: recording::RecordingScope,
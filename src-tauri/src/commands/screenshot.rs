@@ -8,7 +8,8 @@ use tauri::{
 };
 use tauri_plugin_store::StoreExt;
 
-use crate::engine::recording;
+use crate::engine::{project, recording};
+use crate::models::action::ScreenRegion;
 use crate::util::screenshot;
 use crate::AppState;
 
@@ -18,6 +19,11 @@ const RECORDING_CONTROL_WIDTH: f64 = 420.0;
 const RECORDING_CONTROL_HEIGHT: f64 = 220.0;
 const RECORDER_SETUP_WIDTH: f64 = 890.0;
 const RECORDER_SETUP_HEIGHT: f64 = 260.0;
+/// Longest self-timer delay the capture commands accept.
+const MAX_CAPTURE_DELAY_MS: u64 = 10_000;
+
+/// Monitor layout from the last enumeration, used to detect display changes.
+static LAST_MONITORS: Mutex<Option<Vec<screenshot::MonitorInfo>>> = Mutex::new(None);
 
 /// Capture params shared between main window and capture window via managed state.
 #[derive(Default, Clone, Serialize, Deserialize)]
@@ -88,10 +94,82 @@ fn project_root(state: &AppState) -> Result<std::path::PathBuf, String> {
     Ok(view.root.clone())
 }
 
+/// Screenshot options with JPEG quality from the workspace's
+/// `workspaceScreenshotQuality` setting, falling back to the default when no
+/// repo is open or the value is missing. Files are timestamp-named.
+fn screenshot_options(state: &AppState) -> screenshot::ScreenshotOptions {
+    let repo_root = state
+        .current_repo
+        .lock()
+        .ok()
+        .and_then(|repo| repo.as_ref().map(|view| view.root.clone()));
+    let quality = repo_root
+        .map(|root| project::read_repo_settings(&root))
+        .and_then(|settings| settings.get("workspaceScreenshotQuality")?.as_u64())
+        .map(|quality| quality.clamp(1, 100) as u8)
+        .unwrap_or(screenshot::DEFAULT_SCREENSHOT_QUALITY);
+    screenshot::ScreenshotOptions {
+        quality,
+        ..Default::default()
+    }
+}
+
+/// Wait out a capture self-timer, capped at [`MAX_CAPTURE_DELAY_MS`].
+/// Emits `capture-countdown` with the whole seconds left at each second boundary.
+async fn wait_capture_delay(app: &tauri::AppHandle, delay_ms: Option<u64>) {
+    use tauri::Emitter;
+
+    let mut remaining = delay_ms.unwrap_or(0).min(MAX_CAPTURE_DELAY_MS);
+    while remaining > 0 {
+        let seconds_left = remaining.div_ceil(1000);
+        if let Err(e) = app.emit("capture-countdown", seconds_left) {
+            eprintln!("[CAPTURE] could not emit capture-countdown: {}", e);
+        }
+        let step = remaining - (seconds_left - 1) * 1000;
+        tokio::time::sleep(std::time::Duration::from_millis(step)).await;
+        remaining -= step;
+    }
+}
+
+/// Returned by `open_capture_window` instead of opening the overlay when the
+/// requested monitor is gone.
+#[derive(Clone, Debug, Serialize)]
+pub struct MissingMonitorWarning {
+    pub monitor_id: u32,
+    /// The monitors that are connected now.
+    pub monitors: Vec<screenshot::MonitorInfo>,
+}
+
+/// Enumerate monitors and emit `monitors_changed` with the new list when the
+/// layout differs from the previous enumeration.
+fn list_monitors_and_notify(
+    app: &tauri::AppHandle,
+) -> Result<Vec<screenshot::MonitorInfo>, String> {
+    use tauri::Emitter;
+
+    let monitors = screenshot::list_monitors()?;
+    let mut last = LAST_MONITORS.lock().map_err(|e| e.to_string())?;
+    let changed = last
+        .as_deref()
+        .is_some_and(|previous| screenshot::monitors_changed(previous, &monitors));
+    *last = Some(monitors.clone());
+    drop(last);
+    if changed {
+        eprintln!(
+            "[CAPTURE] monitor layout changed: {} monitors",
+            monitors.len()
+        );
+        if let Err(e) = app.emit("monitors_changed", &monitors) {
+            eprintln!("[CAPTURE] could not emit monitors_changed: {}", e);
+        }
+    }
+    Ok(monitors)
+}
+
 #[tauri::command]
-pub async fn list_monitors() -> Result<Vec<screenshot::MonitorInfo>, String> {
+pub async fn list_monitors(app: tauri::AppHandle) -> Result<Vec<screenshot::MonitorInfo>, String> {
     eprintln!("[CAPTURE] list_monitors called");
-    let result = screenshot::list_monitors();
+    let result = list_monitors_and_notify(&app);
     match &result {
         Ok(mons) => eprintln!("[CAPTURE] list_monitors: found {} monitors", mons.len()),
         Err(e) => eprintln!("[CAPTURE] list_monitors FAILED: {}", e),
@@ -99,27 +177,36 @@ pub async fn list_monitors() -> Result<Vec<screenshot::MonitorInfo>, String> {
     result
 }
 
+#[allow(clippy::too_many_arguments)]
 #[tauri::command]
 pub async fn capture_region(
+    app: tauri::AppHandle,
     monitor_id: u32,
     x: i32,
     y: i32,
     width: u32,
     height: u32,
+    delay_ms: Option<u64>,
     state: State<'_, AppState>,
 ) -> Result<String, String> {
     let root = project_root(&state)?;
-    screenshot::capture_region(&root, monitor_id, x, y, width, height)
+    wait_capture_delay(&app, delay_ms).await;
+    let options = screenshot_options(&state);
+    screenshot::capture_region(&root, monitor_id, x, y, width, height, options)
 }
 
 #[tauri::command]
 pub async fn capture_fullscreen(
+    app: tauri::AppHandle,
     monitor_id: u32,
+    delay_ms: Option<u64>,
     state: State<'_, AppState>,
 ) -> Result<String, String> {
     eprintln!("[CAPTURE] capture_fullscreen: monitor_id={}", monitor_id);
     let root = project_root(&state)?;
-    let result = screenshot::capture_fullscreen(&root, monitor_id);
+    wait_capture_delay(&app, delay_ms).await;
+    let options = screenshot_options(&state);
+    let result = screenshot::capture_fullscreen(&root, monitor_id, options);
     match &result {
         Ok(path) => eprintln!("[CAPTURE] capture_fullscreen OK: {}", path),
         Err(e) => eprintln!("[CAPTURE] capture_fullscreen FAILED: {}", e),
@@ -136,7 +223,8 @@ pub async fn capture_all_monitors(
 ) -> Result<std::collections::HashMap<u32, String>, String> {
     eprintln!("[CAPTURE] capture_all_monitors: {:?}", monitor_ids);
     let root = project_root(&state)?;
-    let result = screenshot::capture_all_monitors(&root, &monitor_ids);
+    let options = screenshot_options(&state);
+    let result = screenshot::capture_all_monitors(&root, &monitor_ids, options);
     match &result {
         Ok(map) => eprintln!("[CAPTURE] capture_all_monitors OK: {} results", map.len()),
         Err(e) => eprintln!("[CAPTURE] capture_all_monitors FAILED: {}", e),
@@ -144,6 +232,65 @@ pub async fn capture_all_monitors(
     result
 }
 
+/// Capture every monitor stitched into a single panorama image.
+#[tauri::command]
+pub async fn capture_panorama(state: State<'_, AppState>) -> Result<String, String> {
+    let root = project_root(&state)?;
+    let options = screenshot_options(&state);
+    screenshot::capture_panorama(&root, options)
+}
+
+/// Capture one region per planning row and assign the screenshots in order.
+/// Rows beyond `regions.len()` are left untouched. Returns the new paths.
+///
+/// Files are content-hash named, so recapturing an unchanged screen reuses the
+/// existing file and leaves the sketch diff clean.
+#[tauri::command]
+pub async fn capture_sketch_screenshots(
+    sketch_path: String,
+    monitor_id: u32,
+    regions: Vec<ScreenRegion>,
+    state: State<'_, AppState>,
+) -> Result<Vec<String>, String> {
+    let root = project_root(&state)?;
+    let abs_path = project::safe_resolve(&root, &sketch_path).map_err(|e| e.to_string())?;
+
+    let mut sketch = project::read_sketch(&abs_path).map_err(|e| e.to_string())?;
+    project::ensure_sketch_unlocked(&sketch).map_err(|e| e.to_string())?;
+    // Check locks up front so a locked row doesn't leave orphaned captures behind.
+    for (idx, row) in sketch.rows.iter().take(regions.len()).enumerate() {
+        if row.locked || row.locks.screenshot {
+            return Err(format!(
+                "Planning row {} screenshot is locked. Unlock it before recapturing.",
+                idx + 1
+            ));
+        }
+    }
+
+    let options = screenshot::ScreenshotOptions {
+        naming: screenshot::ScreenshotNaming::ContentHash,
+        ..screenshot_options(&state)
+    };
+    let mut paths = Vec::new();
+    for (row, region) in sketch.rows.iter_mut().zip(&regions) {
+        let path = screenshot::capture_region(
+            &root,
+            monitor_id,
+            region.x,
+            region.y,
+            region.width,
+            region.height,
+            options,
+        )?;
+        row.screenshot = Some(path.clone());
+        paths.push(path);
+    }
+
+    sketch.updated_at = chrono::Utc::now();
+    project::write_sketch(&sketch, &abs_path, &root).map_err(|e| e.to_string())?;
+    Ok(paths)
+}
+
 /// Get capture params (called by the capture window on mount).
 #[tauri::command]
 pub async fn get_capture_params(state: State<'_, CaptureState>) -> Result<CaptureParams, String> {
@@ -175,6 +322,9 @@ pub async fn get_recording_control_params(
 }
 
 /// Open a borderless, always-on-top capture window covering the target monitor.
+///
+/// Monitors are re-enumerated first so the window uses current bounds. If the
+/// monitor was disconnected, no window opens and a warning is returned.
 #[allow(clippy::too_many_arguments)]
 #[tauri::command]
 pub async fn open_capture_window(
@@ -186,12 +336,22 @@ pub async fn open_capture_window(
     phys_h: u32,
     bg_path: String,
     project_root: String,
-) -> Result<(), String> {
+) -> Result<Option<MissingMonitorWarning>, String> {
     eprintln!(
         "[CAPTURE] open_capture_window: monitor={} pos=({},{}) size={}x{}",
         monitor_id, phys_x, phys_y, phys_w, phys_h
     );
 
+    let monitors = list_monitors_and_notify(&app)?;
+    let Some(current) = monitors.iter().find(|m| m.id == monitor_id) else {
+        eprintln!("[CAPTURE] monitor {} is no longer connected", monitor_id);
+        return Ok(Some(MissingMonitorWarning {
+            monitor_id,
+            monitors,
+        }));
+    };
+    let (phys_x, phys_y, phys_w, phys_h) = (current.x, current.y, current.width, current.height);
+
     // Store params in managed state for the capture window to read
     {
         let capture_state = app.state::<CaptureState>();
@@ -255,7 +415,7 @@ pub async fn open_capture_window(
     fit_window_extended_frame_to_physical_bounds(&win, phys_x, phys_y, phys_w, phys_h);
 
     eprintln!("[CAPTURE] window created OK, label={}", win.label());
-    Ok(())
+    Ok(None)
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -899,5 +1059,16 @@ pub async fn crop_screenshot(
     state: State<'_, AppState>,
 ) -> Result<String, String> {
     let root = project_root(&state)?;
-    screenshot::crop_screenshot(&root, &source_path, x, y, width, height)
+    let options = screenshot_options(&state);
+    screenshot::crop_screenshot(&root, &source_path, x, y, width, height, options)
+}
+
+/// Copy a captured screenshot to the system clipboard.
+#[tauri::command]
+pub async fn copy_screenshot_to_clipboard(
+    source_path: String,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let root = project_root(&state)?;
+    screenshot::copy_to_clipboard(&root, &source_path)
 }
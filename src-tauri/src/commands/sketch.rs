@@ -2,12 +2,15 @@
 //!
 //! Sketches are `.sk` files in the project folder, identified by relative path.
 
-use tauri::State;
+use tauri::{AppHandle, State};
 use tauri_plugin_auditaur::auditaur_command;
 
 use crate::engine::{agent::tools::normalize_visual_document_for_save, project};
 use crate::models::script::ProjectView;
-use crate::models::sketch::{DocumentMetadata, PlanningCellLocks, Sketch, SketchSummary};
+use crate::models::sketch::{
+    DocumentMetadata, PlanningCellLocks, Sketch, SketchState, SketchSummary,
+};
+use crate::util::document_events::emit_document_changed;
 use crate::AppState;
 
 /// Helper: get the project root from current state.
@@ -46,6 +49,7 @@ pub async fn update_sketch(
     description: Option<serde_json::Value>,
     rows: Option<Vec<crate::models::sketch::PlanningRow>>,
     metadata: Option<DocumentMetadata>,
+    app: AppHandle,
     state: State<'_, AppState>,
 ) -> Result<(), String> {
     let root = project_root(&state)?;
@@ -71,6 +75,7 @@ pub async fn update_sketch(
     sketch.updated_at = chrono::Utc::now();
 
     project::write_sketch(&sketch, &abs_path, &root).map_err(|e| e.to_string())?;
+    emit_document_changed(&app, "sketch", &relative_path);
     Ok(())
 }
 
@@ -92,6 +97,46 @@ pub async fn update_sketch_title(
     Ok(())
 }
 
+/// Move a sketch along its lifecycle, rejecting transitions that skip stages.
+#[tauri::command]
+pub async fn transition_sketch_state(
+    relative_path: String,
+    to: SketchState,
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let root = project_root(&state)?;
+    let abs_path = project::safe_resolve(&root, &relative_path).map_err(|e| e.to_string())?;
+
+    let mut sketch = project::read_sketch(&abs_path).map_err(|e| e.to_string())?;
+    project::ensure_sketch_unlocked(&sketch).map_err(|e| e.to_string())?;
+    if !sketch.state.can_transition_to(&to) {
+        return Err(format!(
+            "Cannot move a sketch from {} to {}",
+            sketch.state.as_str(),
+            to.as_str()
+        ));
+    }
+    sketch.state = to;
+    sketch.updated_at = chrono::Utc::now();
+
+    project::write_sketch(&sketch, &abs_path, &root).map_err(|e| e.to_string())?;
+    emit_document_changed(&app, "sketch", &relative_path);
+    Ok(())
+}
+
+/// Zip every row screenshot of a sketch as `step_{n}.{ext}` for handoff.
+#[tauri::command]
+pub async fn export_sketch_images(
+    relative_path: String,
+    out_path: String,
+    state: State<'_, AppState>,
+) -> Result<project::SketchImagesExport, String> {
+    let root = project_root(&state)?;
+    project::export_sketch_images_zip(&root, &relative_path, std::path::Path::new(&out_path))
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub async fn delete_sketch(
     relative_path: String,
@@ -119,6 +164,13 @@ pub async fn list_sketches(state: State<'_, AppState>) -> Result<Vec<SketchSumma
     project::scan_sketches(&root).map_err(|e| e.to_string())
 }
 
+/// List sketches under `sketches/` with their folder-relative paths intact.
+#[auditaur_command(skip_all, err)]
+pub async fn list_sketches_tree(state: State<'_, AppState>) -> Result<Vec<SketchSummary>, String> {
+    let root = project_root(&state)?;
+    project::scan_sketches_tree(&root).map_err(|e| e.to_string())
+}
+
 #[auditaur_command(skip_all, err)]
 pub async fn get_sketch(
     relative_path: String,
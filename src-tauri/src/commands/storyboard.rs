@@ -44,6 +44,75 @@ fn set_referenced_sketches_lock(
     Ok(())
 }
 
+/// Move the first reference to `sketch_path` to a new location.
+///
+/// `to_section_index` selects a section item to insert into; `None` moves the
+/// sketch to the top level as a loose `SketchRef`. Positions past the end append.
+fn move_sketch_ref(
+    storyboard: &mut Storyboard,
+    sketch_path: &str,
+    to_section_index: Option<usize>,
+    to_position: usize,
+) -> Result<(), String> {
+    let mut target_section = to_section_index;
+    if let Some(index) = target_section {
+        if !matches!(
+            storyboard.items.get(index),
+            Some(StoryboardItem::Section { .. })
+        ) {
+            return Err(format!("Item {index} is not a section"));
+        }
+    }
+
+    let source = storyboard
+        .items
+        .iter()
+        .enumerate()
+        .find_map(|(index, item)| match item {
+            StoryboardItem::SketchRef { path } if path == sketch_path => Some((index, None)),
+            StoryboardItem::Section { sketches, .. } => sketches
+                .iter()
+                .position(|path| path == sketch_path)
+                .map(|offset| (index, Some(offset))),
+            _ => None,
+        })
+        .ok_or_else(|| format!("Sketch not in storyboard: {sketch_path}"))?;
+
+    match source {
+        (index, None) => {
+            storyboard.items.remove(index);
+            if let Some(section) = target_section.as_mut() {
+                if index < *section {
+                    *section -= 1;
+                }
+            }
+        }
+        (index, Some(offset)) => {
+            if let StoryboardItem::Section { sketches, .. } = &mut storyboard.items[index] {
+                sketches.remove(offset);
+            }
+        }
+    }
+
+    match target_section {
+        Some(index) => {
+            if let StoryboardItem::Section { sketches, .. } = &mut storyboard.items[index] {
+                sketches.insert(to_position.min(sketches.len()), sketch_path.to_string());
+            }
+        }
+        None => {
+            let position = to_position.min(storyboard.items.len());
+            storyboard.items.insert(
+                position,
+                StoryboardItem::SketchRef {
+                    path: sketch_path.to_string(),
+                },
+            );
+        }
+    }
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn create_storyboard(
     relative_path: String,
@@ -74,6 +143,32 @@ pub async fn get_storyboard(
     project::read_storyboard(&abs_path).map_err(|e| e.to_string())
 }
 
+/// Return sketch paths referenced by the storyboard that are missing on disk.
+#[tauri::command]
+pub async fn validate_storyboard(
+    relative_path: String,
+    state: State<'_, AppState>,
+) -> Result<Vec<String>, String> {
+    let root = project_root(&state)?;
+    let abs_path = project::safe_resolve(&root, &relative_path).map_err(|e| e.to_string())?;
+
+    let storyboard = project::read_storyboard(&abs_path).map_err(|e| e.to_string())?;
+    Ok(project::validate_storyboard(&root, &storyboard))
+}
+
+/// Render the storyboard and its sketches as one Markdown document.
+#[tauri::command]
+pub async fn export_storyboard_markdown(
+    relative_path: String,
+    state: State<'_, AppState>,
+) -> Result<String, String> {
+    let root = project_root(&state)?;
+    let abs_path = project::safe_resolve(&root, &relative_path).map_err(|e| e.to_string())?;
+
+    let storyboard = project::read_storyboard(&abs_path).map_err(|e| e.to_string())?;
+    project::storyboard_to_markdown(&root, &storyboard).map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub async fn update_storyboard(
     relative_path: String,
@@ -255,6 +350,82 @@ pub async fn reorder_storyboard_items(
     Ok(())
 }
 
+#[tauri::command]
+pub async fn move_sketch_in_storyboard(
+    storyboard_path: String,
+    sketch_path: String,
+    to_section_index: Option<usize>,
+    to_position: usize,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let root = project_root(&state)?;
+    let sb_abs = project::safe_resolve(&root, &storyboard_path).map_err(|e| e.to_string())?;
+
+    let mut sb = project::read_storyboard(&sb_abs).map_err(|e| e.to_string())?;
+    project::ensure_storyboard_unlocked(&sb).map_err(|e| e.to_string())?;
+    move_sketch_ref(&mut sb, &sketch_path, to_section_index, to_position)?;
+    sb.updated_at = Utc::now();
+
+    project::write_storyboard(&sb, &sb_abs, &root).map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Move the section at item `from_index` to the slot of the section at
+/// `to_index`, shifting the sections in between.
+///
+/// Only section slots are permuted; loose `SketchRef` items keep their
+/// positions. Both indices must point at sections.
+fn move_section_item(
+    storyboard: &mut Storyboard,
+    from_index: usize,
+    to_index: usize,
+) -> Result<(), String> {
+    let slots: Vec<usize> = storyboard
+        .items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| matches!(item, StoryboardItem::Section { .. }))
+        .map(|(index, _)| index)
+        .collect();
+    let ordinal = |index: usize| {
+        slots
+            .iter()
+            .position(|slot| *slot == index)
+            .ok_or_else(|| format!("Item {index} is not a section"))
+    };
+    let (from, to) = (ordinal(from_index)?, ordinal(to_index)?);
+
+    let mut sections: Vec<StoryboardItem> = slots
+        .iter()
+        .map(|slot| storyboard.items[*slot].clone())
+        .collect();
+    let moved = sections.remove(from);
+    sections.insert(to, moved);
+    for (slot, section) in slots.into_iter().zip(sections) {
+        storyboard.items[slot] = section;
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn move_section(
+    storyboard_path: String,
+    from_index: usize,
+    to_index: usize,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let root = project_root(&state)?;
+    let sb_abs = project::safe_resolve(&root, &storyboard_path).map_err(|e| e.to_string())?;
+
+    let mut sb = project::read_storyboard(&sb_abs).map_err(|e| e.to_string())?;
+    project::ensure_storyboard_unlocked(&sb).map_err(|e| e.to_string())?;
+    move_section_item(&mut sb, from_index, to_index)?;
+    sb.updated_at = Utc::now();
+
+    project::write_storyboard(&sb, &sb_abs, &root).map_err(|e| e.to_string())?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -294,4 +465,79 @@ mod tests {
         assert!(!unlocked.rows[0].locked);
         assert!(!unlocked.rows[0].locks.any());
     }
+
+    fn section(title: &str, sketches: &[&str]) -> StoryboardItem {
+        StoryboardItem::Section {
+            title: title.into(),
+            description: String::new(),
+            sketches: sketches.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn move_sketch_ref_between_sections() {
+        let mut sb = Storyboard::new("Demo");
+        sb.items = vec![
+            section("Setup", &["a.sk", "b.sk"]),
+            section("Flows", &["c.sk"]),
+        ];
+
+        move_sketch_ref(&mut sb, "b.sk", Some(1), 0).unwrap();
+
+        assert_eq!(sb.items[0], section("Setup", &["a.sk"]));
+        assert_eq!(sb.items[1], section("Flows", &["b.sk", "c.sk"]));
+    }
+
+    #[test]
+    fn move_sketch_ref_from_top_level_into_later_section() {
+        let mut sb = Storyboard::new("Demo");
+        sb.items = vec![
+            StoryboardItem::SketchRef {
+                path: "intro.sk".into(),
+            },
+            section("Flows", &["c.sk"]),
+        ];
+
+        move_sketch_ref(&mut sb, "intro.sk", Some(1), 5).unwrap();
+        assert_eq!(sb.items, vec![section("Flows", &["c.sk", "intro.sk"])]);
+
+        move_sketch_ref(&mut sb, "c.sk", None, 0).unwrap();
+        assert_eq!(
+            sb.items,
+            vec![
+                StoryboardItem::SketchRef {
+                    path: "c.sk".into()
+                },
+                section("Flows", &["intro.sk"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn move_section_keeps_loose_refs_in_place() {
+        let mut sb = Storyboard::new("Demo");
+        let loose = StoryboardItem::SketchRef {
+            path: "intro.sk".into(),
+        };
+        sb.items = vec![
+            section("Setup", &["a.sk"]),
+            loose.clone(),
+            section("Flows", &["b.sk"]),
+            section("Wrap", &["c.sk"]),
+        ];
+
+        move_section_item(&mut sb, 0, 3).unwrap();
+        assert_eq!(
+            sb.items,
+            vec![
+                section("Flows", &["b.sk"]),
+                loose.clone(),
+                section("Wrap", &["c.sk"]),
+                section("Setup", &["a.sk"]),
+            ]
+        );
+
+        assert!(move_section_item(&mut sb, 1, 0).is_err());
+        assert!(move_section_item(&mut sb, 0, 9).is_err());
+    }
 }
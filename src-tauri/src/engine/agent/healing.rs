@@ -0,0 +1,245 @@
+//! Selector healing — ask the LLM for replacement selectors when replay
+//! can no longer resolve any of an action's recorded strategies.
+//!
+//! The current page is snapshotted through the sidecar, the model proposes
+//! candidates for the original intent, and only candidates that resolve to
+//! exactly one element on the live page are returned.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::engine::agent::llm::{self, ChatMessage, Provider};
+use crate::engine::interaction;
+use crate::engine::video_import::extract_json_object;
+use crate::models::action::{Action, SelectorStrategy};
+use crate::util::sidecar::SidecarClient;
+
+/// Maximum LLM round-trips per action before giving up.
+pub const MAX_HEALING_ATTEMPTS: usize = 3;
+
+/// Upper bound on candidates validated per round-trip.
+const MAX_CANDIDATES_PER_ATTEMPT: usize = 8;
+
+/// Characters of page HTML sent to the model.
+const SNAPSHOT_MAX_CHARS: usize = 20_000;
+
+const HEALING_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Deserialize)]
+struct HealingResponse {
+    #[serde(default)]
+    selectors: Vec<SelectorStrategy>,
+}
+
+/// Propose, validate, and return selectors that uniquely match the element
+/// `action` originally targeted.
+///
+/// Returns an empty list when the action has no selectors to heal or no
+/// candidate resolves within [`MAX_HEALING_ATTEMPTS`] rounds.
+pub async fn heal(
+    sidecar: &impl SidecarClient,
+    action: &Action,
+    provider: Arc<dyn Provider + Send + Sync>,
+) -> anyhow::Result<Vec<SelectorStrategy>> {
+    let Some(original) = action.selectors() else {
+        return Ok(Vec::new());
+    };
+
+    let snapshot = sidecar
+        .request(
+            "browser.snapshot",
+            serde_json::json!({ "max_chars": SNAPSHOT_MAX_CHARS }),
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!("Page snapshot failed: {e}"))?;
+
+    let mut rejected: Vec<SelectorStrategy> = original.to_vec();
+    for attempt in 1..=MAX_HEALING_ATTEMPTS {
+        let messages = vec![
+            ChatMessage::system(SELECTOR_HEALING_PROMPT),
+            ChatMessage::user(&build_healing_prompt(action, &snapshot, &rejected)),
+        ];
+        let response = tokio::time::timeout(
+            HEALING_TIMEOUT,
+            llm::simple_chat(provider.clone(), messages),
+        )
+        .await
+        .map_err(|_| anyhow::anyhow!("Selector healing timed out"))?
+        .map_err(|e| anyhow::anyhow!("Selector healing request failed: {e}"))?;
+
+        let candidates = match response.text().map(parse_candidates) {
+            Some(Ok(candidates)) => candidates,
+            Some(Err(err)) => {
+                tracing::warn!(attempt, "unusable selector healing response: {err}");
+                continue;
+            }
+            None => continue,
+        };
+
+        let mut healed = Vec::new();
+        for candidate in candidates.into_iter().take(MAX_CANDIDATES_PER_ATTEMPT) {
+            if rejected.contains(&candidate) || healed.contains(&candidate) {
+                continue;
+            }
+            if interaction::validate_selector(sidecar, &candidate)
+                .await?
+                .unique
+            {
+                healed.push(candidate);
+            } else {
+                rejected.push(candidate);
+            }
+        }
+        if !healed.is_empty() {
+            return Ok(healed);
+        }
+    }
+
+    Ok(Vec::new())
+}
+
+fn parse_candidates(text: &str) -> anyhow::Result<Vec<SelectorStrategy>> {
+    let response: HealingResponse = serde_json::from_str(extract_json_object(text)?)?;
+    Ok(response
+        .selectors
+        .into_iter()
+        .filter(|strategy| !matches!(strategy, SelectorStrategy::UiaTreePath(_)))
+        .collect())
+}
+
+fn build_healing_prompt(
+    action: &Action,
+    snapshot: &serde_json::Value,
+    rejected: &[SelectorStrategy],
+) -> String {
+    let action_json = serde_json::to_string_pretty(action).unwrap_or_default();
+    let rejected_json = serde_json::to_string(rejected).unwrap_or_default();
+    let url = snapshot.get("url").and_then(|v| v.as_str()).unwrap_or("");
+    let title = snapshot.get("title").and_then(|v| v.as_str()).unwrap_or("");
+    let html = snapshot.get("html").and_then(|v| v.as_str()).unwrap_or("");
+    format!(
+        "Recorded action:\n{action_json}\n\n\
+         Selectors that do not resolve (do not repeat):\n{rejected_json}\n\n\
+         Page URL: {url}\nPage title: {title}\n\nPage HTML:\n{html}"
+    )
+}
+
+const SELECTOR_HEALING_PROMPT: &str = r#"You repair broken selectors for CutReady's demo replay.
+
+A recorded browser action no longer matches the page. Given the action, the selectors that failed, and the current page HTML, propose selectors that target the same element the action originally meant.
+
+Rules:
+- Return only valid JSON. No markdown fences, no prose outside JSON.
+- Each selector must match exactly one element on the page.
+- Prefer stable hooks: data-testid, id, aria-label, then short CSS, then visible text, then XPath.
+- Order candidates from most to least likely.
+
+Shape:
+{"selectors": [{"strategy": "DataTestId", "value": "save"}, {"strategy": "CssSelector", "value": "form button[type=submit]"}]}
+
+Valid strategies: CssSelector, XPath, AccessibilityId, AccessibilityName, DataTestId, TextContent."#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::agent::llm::fake::FakeProvider;
+    use serde_json::json;
+    use std::sync::Mutex;
+
+    /// Sidecar stub where only `accepted` resolves uniquely.
+    struct StubSidecar {
+        accepted: &'static str,
+        validated: Mutex<Vec<String>>,
+    }
+
+    impl SidecarClient for StubSidecar {
+        async fn request(
+            &self,
+            method: &str,
+            params: serde_json::Value,
+        ) -> Result<serde_json::Value, String> {
+            match method {
+                "browser.snapshot" => Ok(json!({
+                    "url": "https://example.com",
+                    "title": "Example",
+                    "html": "<button data-testid=\"save\">Save</button>",
+                })),
+                "browser.validateSelector" => {
+                    let value = params["value"].as_str().unwrap_or_default().to_string();
+                    let count = u64::from(value == self.accepted);
+                    self.validated.lock().unwrap().push(value);
+                    Ok(json!({ "match_count": count }))
+                }
+                other => Err(format!("unexpected method {other}")),
+            }
+        }
+    }
+
+    fn stub_sidecar() -> StubSidecar {
+        StubSidecar {
+            accepted: "save",
+            validated: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn click(value: &str) -> Action {
+        Action::BrowserClick {
+            selectors: vec![SelectorStrategy::CssSelector(value.into())],
+        }
+    }
+
+    #[tokio::test]
+    async fn heal_returns_candidates_that_validate() {
+        // A single queued reply: a second round-trip would fail the request.
+        let provider = Arc::new(FakeProvider::new(vec![r##"{"selectors": [
+            {"strategy": "CssSelector", "value": "#old-save"},
+            {"strategy": "DataTestId", "value": "save"},
+            {"strategy": "TextContent", "value": "Save draft"}
+        ]}"##
+            .into()]));
+        let sidecar = stub_sidecar();
+
+        let healed = heal(&sidecar, &click("#old-save"), provider).await.unwrap();
+
+        assert_eq!(healed, vec![SelectorStrategy::DataTestId("save".into())]);
+        // The original selector is known broken and is not re-validated.
+        assert_eq!(
+            *sidecar.validated.lock().unwrap(),
+            vec!["save".to_string(), "Save draft".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn heal_gives_up_after_max_attempts() {
+        let replies = (1..=MAX_HEALING_ATTEMPTS)
+            .map(|attempt| {
+                format!(r##"{{"selectors": [{{"strategy": "CssSelector", "value": "#nope-{attempt}"}}]}}"##)
+            })
+            .collect();
+        let provider = Arc::new(FakeProvider::new(replies));
+        let sidecar = stub_sidecar();
+
+        let healed = heal(&sidecar, &click("#old-save"), provider).await.unwrap();
+
+        assert!(healed.is_empty());
+        assert_eq!(
+            sidecar.validated.lock().unwrap().len(),
+            MAX_HEALING_ATTEMPTS
+        );
+    }
+
+    #[tokio::test]
+    async fn heal_skips_actions_without_selectors() {
+        // No queued replies: any LLM call would fail the request.
+        let provider = Arc::new(FakeProvider::new(Vec::new()));
+        let sidecar = stub_sidecar();
+        let action = Action::BrowserNavigate {
+            url: "https://example.com".into(),
+        };
+
+        assert!(heal(&sidecar, &action, provider).await.unwrap().is_empty());
+        assert!(sidecar.validated.lock().unwrap().is_empty());
+    }
+}
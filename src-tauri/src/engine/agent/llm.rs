@@ -7,6 +7,9 @@
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
+#[cfg(test)]
+pub mod fake;
+
 // ---------------------------------------------------------------------------
 // Shared types — re-exported from agentive
 // ---------------------------------------------------------------------------
@@ -47,6 +50,46 @@ pub struct LlmConfig {
     pub bearer_token: Option<String>,
 }
 
+/// A project's pinned LLM configuration as shown to the frontend, with the
+/// API key replaced by whether one is stored.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ProjectLlmConfigView {
+    pub provider: LlmProvider,
+    pub endpoint: String,
+    pub model: String,
+    pub has_key: bool,
+}
+
+impl From<&LlmConfig> for ProjectLlmConfigView {
+    fn from(config: &LlmConfig) -> Self {
+        Self {
+            provider: config.provider.clone(),
+            endpoint: config.endpoint.clone(),
+            model: config.model.clone(),
+            has_key: !config.api_key.trim().is_empty(),
+        }
+    }
+}
+
+/// Prefer a project's pinned configuration over the global one. A pinned
+/// config without its own key reuses the global credentials when both name the
+/// same provider.
+pub fn prefer_project_config(global: LlmConfig, project: Option<LlmConfig>) -> LlmConfig {
+    match project {
+        None => global,
+        Some(project)
+            if project.api_key.trim().is_empty() && project.provider == global.provider =>
+        {
+            LlmConfig {
+                api_key: global.api_key,
+                bearer_token: global.bearer_token,
+                ..project
+            }
+        }
+        Some(project) => project,
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Provider + discovery
 // ---------------------------------------------------------------------------
@@ -92,6 +135,110 @@ pub async fn list_models(config: &LlmConfig) -> Result<Vec<ModelInfo>, String> {
         .map(|models| models.into_iter().map(normalize_model_info).collect())
 }
 
+// ---------------------------------------------------------------------------
+// Connection check
+// ---------------------------------------------------------------------------
+
+/// How long a connection check waits for the provider to answer.
+pub const CONNECTION_CHECK_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(20);
+
+/// Result of a connection check, shown by the settings "Test connection" button.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ConnectionCheck {
+    pub ok: bool,
+    pub latency_ms: u64,
+    pub model: String,
+    pub error: Option<String>,
+}
+
+/// Why a connection check failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConnectionFailure {
+    /// 401/403: the key or token was rejected.
+    Auth,
+    /// 404: the deployment or model doesn't exist at the endpoint.
+    NotFound,
+    /// Any other HTTP error status.
+    Rejected,
+    /// No HTTP status: DNS, TLS, connection, or timeout failures.
+    Network,
+}
+
+/// Send a one-word chat to the provider to verify credentials and the model.
+pub async fn check_connection(
+    provider: Arc<dyn Provider + Send + Sync>,
+    config: &LlmConfig,
+) -> ConnectionCheck {
+    let model = effective_model(config).to_string();
+    let started = std::time::Instant::now();
+    let result = tokio::time::timeout(
+        CONNECTION_CHECK_TIMEOUT,
+        simple_chat(provider, vec![ChatMessage::user("ping")]),
+    )
+    .await;
+    let latency_ms = started.elapsed().as_millis() as u64;
+
+    let error = match result {
+        Ok(Ok(_)) => None,
+        Ok(Err(err)) => Some(connection_error_message(config, &model, &err)),
+        Err(_) => Some(format!(
+            "The provider did not answer within {} seconds. Check the endpoint URL and your network connection.",
+            CONNECTION_CHECK_TIMEOUT.as_secs()
+        )),
+    };
+    ConnectionCheck {
+        ok: error.is_none(),
+        latency_ms,
+        model,
+        error,
+    }
+}
+
+fn classify_connection_error(err: &agentive::AgentError) -> ConnectionFailure {
+    match err {
+        agentive::AgentError::Api {
+            status: 401 | 403, ..
+        } => ConnectionFailure::Auth,
+        agentive::AgentError::Api { status: 404, .. } => ConnectionFailure::NotFound,
+        agentive::AgentError::Api { .. } => ConnectionFailure::Rejected,
+        // Streaming failures carry the status in the message, e.g. "API error (401): ...".
+        agentive::AgentError::Stream(message) => match message
+            .split_once("API error (")
+            .and_then(|(_, rest)| rest.split_once(')'))
+            .and_then(|(code, _)| code.parse::<u16>().ok())
+        {
+            Some(401 | 403) => ConnectionFailure::Auth,
+            Some(404) => ConnectionFailure::NotFound,
+            Some(_) => ConnectionFailure::Rejected,
+            None => ConnectionFailure::Network,
+        },
+        _ => ConnectionFailure::Network,
+    }
+}
+
+fn connection_error_message(config: &LlmConfig, model: &str, err: &agentive::AgentError) -> String {
+    let azure = matches!(
+        config.provider,
+        LlmProvider::AzureOpenai | LlmProvider::MicrosoftFoundry
+    );
+    match classify_connection_error(err) {
+        ConnectionFailure::Auth if azure => format!(
+            "Authentication failed. Check the API key, or sign in again if you use Microsoft Entra ID, and make sure your account has access to the resource. ({err})"
+        ),
+        ConnectionFailure::Auth => format!("Authentication failed. Check the API key. ({err})"),
+        ConnectionFailure::NotFound if azure => format!(
+            "Deployment \"{model}\" was not found. Check the deployment name and that the endpoint points at the right resource. ({err})"
+        ),
+        ConnectionFailure::NotFound => format!(
+            "Model \"{model}\" was not found. Check the model name and endpoint. ({err})"
+        ),
+        ConnectionFailure::Rejected => format!("The provider rejected the test request: {err}"),
+        ConnectionFailure::Network => format!(
+            "Could not reach the provider. Check the endpoint URL and your network connection. ({err})"
+        ),
+    }
+}
+
 fn effective_endpoint(config: &LlmConfig) -> &str {
     let endpoint = config.endpoint.trim_end_matches('/');
     if endpoint.is_empty() {
@@ -197,6 +344,93 @@ mod tests {
         }
     }
 
+    #[test]
+    fn project_config_reuses_global_credentials_for_same_provider() {
+        let global = azure_config(Some("global-token"));
+        let pinned = LlmConfig {
+            api_key: String::new(),
+            model: "gpt-4.1".into(),
+            bearer_token: None,
+            ..azure_config(None)
+        };
+
+        let effective = prefer_project_config(global.clone(), Some(pinned));
+        assert_eq!(effective.model, "gpt-4.1");
+        assert_eq!(effective.api_key, "test-key");
+        assert_eq!(effective.bearer_token.as_deref(), Some("global-token"));
+
+        let effective = prefer_project_config(global, None);
+        assert_eq!(effective.model, "gpt-4o");
+    }
+
+    // ── connection check ─────────────────────────────────────────
+
+    #[test]
+    fn connection_errors_are_classified_by_status() {
+        let api_error = |status| agentive::AgentError::Api {
+            status,
+            message: "error".into(),
+        };
+        assert_eq!(
+            classify_connection_error(&api_error(401)),
+            ConnectionFailure::Auth
+        );
+        assert_eq!(
+            classify_connection_error(&api_error(403)),
+            ConnectionFailure::Auth
+        );
+        assert_eq!(
+            classify_connection_error(&api_error(404)),
+            ConnectionFailure::NotFound
+        );
+        assert_eq!(
+            classify_connection_error(&api_error(429)),
+            ConnectionFailure::Rejected
+        );
+        assert_eq!(
+            classify_connection_error(&agentive::AgentError::Stream(
+                "API error (401): Access denied due to invalid subscription key".into()
+            )),
+            ConnectionFailure::Auth
+        );
+        assert_eq!(
+            classify_connection_error(&agentive::AgentError::Stream(
+                "error sending request: dns error".into()
+            )),
+            ConnectionFailure::Network
+        );
+    }
+
+    #[test]
+    fn azure_not_found_names_the_deployment() {
+        let api_error = |status| agentive::AgentError::Api {
+            status,
+            message: "error".into(),
+        };
+        let message = connection_error_message(&azure_config(None), "gpt-4o", &api_error(404));
+        assert!(message.starts_with("Deployment \"gpt-4o\" was not found"));
+
+        let message = connection_error_message(&azure_config(None), "gpt-4o", &api_error(401));
+        assert!(message.contains("Microsoft Entra ID"));
+    }
+
+    #[tokio::test]
+    async fn connection_check_reports_success_and_failure() {
+        let config = azure_config(None);
+
+        let check = check_connection(Arc::new(fake::FakeProvider::echo()), &config).await;
+        assert!(check.ok);
+        assert_eq!(check.model, "gpt-4o");
+        assert_eq!(check.error, None);
+
+        let check = check_connection(Arc::new(fake::FakeProvider::new(Vec::new())), &config).await;
+        assert!(!check.ok);
+        assert!(check
+            .error
+            .unwrap()
+            .starts_with("Could not reach the provider"));
+    }
+
     // ── build_provider routing ───────────────────────────────────
 
     #[test]
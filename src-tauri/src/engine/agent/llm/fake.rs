@@ -0,0 +1,145 @@
+//! In-memory provider for deterministic agent tests.
+//!
+//! Replies come from a queue in order, or echo the last user message, so
+//! cleanup, narrative, and healing paths can run without a real key.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use tokio::sync::mpsc;
+
+use super::{ChatMessage, Provider};
+
+/// Provider that answers from a fixed script instead of the network.
+pub struct FakeProvider {
+    responses: Mutex<VecDeque<String>>,
+    echo: bool,
+    structured: bool,
+}
+
+impl FakeProvider {
+    /// Reply with `responses` in order, one per request.
+    pub fn new(responses: Vec<String>) -> Self {
+        Self {
+            responses: Mutex::new(responses.into()),
+            echo: false,
+            structured: false,
+        }
+    }
+
+    /// Like [`FakeProvider::new`], but every reply must be valid JSON. An
+    /// invalid reply fails the request, as a structured completion would.
+    pub fn structured(responses: Vec<String>) -> Self {
+        Self {
+            structured: true,
+            ..Self::new(responses)
+        }
+    }
+
+    /// Reply with the text of the last user message.
+    pub fn echo() -> Self {
+        Self {
+            echo: true,
+            ..Self::new(Vec::new())
+        }
+    }
+
+    fn next_reply(&self, request: &agentive::ChatRequest) -> Result<String, agentive::AgentError> {
+        let reply = if self.echo {
+            last_user_text(request)
+        } else {
+            let queued = self.responses.lock().unwrap().pop_front();
+            queued.ok_or_else(|| agentive::AgentError::Stream("no queued fake responses".into()))?
+        };
+        if self.structured {
+            serde_json::from_str::<serde_json::Value>(&reply).map_err(|err| {
+                agentive::AgentError::Stream(format!("fake response is not valid JSON: {err}"))
+            })?;
+        }
+        Ok(reply)
+    }
+}
+
+fn last_user_text(request: &agentive::ChatRequest) -> String {
+    request
+        .messages
+        .iter()
+        .rev()
+        .find(|message| message.role == "user")
+        .and_then(|message| message.text())
+        .unwrap_or_default()
+        .to_string()
+}
+
+#[async_trait]
+impl Provider for FakeProvider {
+    async fn chat(
+        &self,
+        request: agentive::ChatRequest,
+        tx: mpsc::Sender<agentive::ChatEvent>,
+        _cancel: &agentive::CancellationToken,
+    ) -> Result<(), agentive::AgentError> {
+        let reply = self.next_reply(&request)?;
+        tx.send(agentive::ChatEvent::Done {
+            response: agentive::ChatResponse {
+                message: ChatMessage::assistant(reply.as_str()),
+                usage: None,
+            },
+        })
+        .await
+        .map_err(|err| agentive::AgentError::Stream(err.to_string()))
+    }
+
+    fn name(&self) -> &str {
+        "fake"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::agent::llm::simple_chat;
+    use std::sync::Arc;
+
+    fn ask(text: &str) -> Vec<ChatMessage> {
+        vec![ChatMessage::system("be brief"), ChatMessage::user(text)]
+    }
+
+    #[tokio::test]
+    async fn queued_responses_are_returned_in_order() {
+        let provider = Arc::new(FakeProvider::new(vec!["first".into(), "second".into()]));
+
+        let first = simple_chat(provider.clone(), ask("a")).await.unwrap();
+        let second = simple_chat(provider.clone(), ask("b")).await.unwrap();
+
+        assert_eq!(first.text(), Some("first"));
+        assert_eq!(second.text(), Some("second"));
+        assert!(simple_chat(provider, ask("c")).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn structured_responses_must_be_json() {
+        let provider = Arc::new(FakeProvider::structured(vec![
+            r#"{"ok": true}"#.into(),
+            "not json".into(),
+        ]));
+
+        let reply = simple_chat(provider.clone(), ask("a")).await.unwrap();
+        let value: serde_json::Value = serde_json::from_str(reply.text().unwrap()).unwrap();
+        assert_eq!(value["ok"], true);
+        assert!(simple_chat(provider, ask("b")).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn echo_returns_last_user_message() {
+        let provider = Arc::new(FakeProvider::echo());
+        let mut messages = ask("first question");
+        messages.push(ChatMessage::assistant("answer"));
+        messages.push(ChatMessage::user("follow up"));
+
+        let reply = simple_chat(provider, messages).await.unwrap();
+
+        assert_eq!(reply.text(), Some("follow up"));
+    }
+}
@@ -3,6 +3,7 @@
 //! Core module providing the LLM client for chat completions with
 //! function calling, streaming, and the agentic tool loop.
 
+pub mod healing;
 pub mod llm;
 pub mod runner;
 pub mod sanitize;
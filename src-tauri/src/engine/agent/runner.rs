@@ -32,6 +32,10 @@ const MAX_DELEGATION_DEPTH: usize = 2;
 const CONTEXT_FAILURE_RETRY_FRACTION_NUMERATOR: usize = 2;
 const CONTEXT_FAILURE_RETRY_FRACTION_DENOMINATOR: usize = 3;
 
+/// Rough characters per token when comparing a request with a model's
+/// reported context window.
+const CONTEXT_CHARS_PER_TOKEN: usize = 4;
+
 // ---------------------------------------------------------------------------
 // Event types
 // ---------------------------------------------------------------------------
@@ -115,12 +119,13 @@ pub async fn run(
     web_access: &WebAccessConfig,
     mutation_tools_enabled: bool,
     max_tool_rounds: usize,
+    context_tokens: Option<usize>,
     context_items: Vec<agentive::ContextItem>,
     run_id: Option<String>,
     agent_state: Option<AgentStateStore>,
     cancellation: agentive::CancellationToken,
     emit: impl Fn(AgentEvent) + Send + Sync + 'static,
-) -> Result<agentive::RunnerResult, String> {
+) -> Result<agentive::RunnerResult, RunError> {
     let emit = Arc::new(emit);
     run_inner(
         provider,
@@ -137,6 +142,7 @@ pub async fn run(
         web_access,
         mutation_tools_enabled,
         max_tool_rounds,
+        context_tokens,
         context_items,
         run_id,
         agent_state,
@@ -146,6 +152,20 @@ pub async fn run(
     .await
 }
 
+/// The tools offered to an agent at the start of a run.
+pub fn run_tools(
+    agent_id: &str,
+    web_access: &WebAccessConfig,
+    mutation_tools_enabled: bool,
+) -> Vec<agentive::Tool> {
+    let project_workspace_tools_enabled = agent_id.eq_ignore_ascii_case("writer");
+    tools::all_tools(
+        web_access.search_enabled,
+        project_workspace_tools_enabled && mutation_tools_enabled,
+        mutation_tools_enabled,
+    )
+}
+
 /// Internal runner with depth tracking for sub-agent delegation.
 #[allow(clippy::too_many_arguments)]
 fn run_inner<'a>(
@@ -163,26 +183,54 @@ fn run_inner<'a>(
     web_access: &'a WebAccessConfig,
     mutation_tools_enabled: bool,
     max_tool_rounds: usize,
+    context_tokens: Option<usize>,
     context_items: Vec<agentive::ContextItem>,
     run_id: Option<String>,
     agent_state: Option<AgentStateStore>,
     cancellation: agentive::CancellationToken,
     emit: Arc<dyn Fn(AgentEvent) + Send + Sync + 'static>,
 ) -> std::pin::Pin<
-    Box<dyn std::future::Future<Output = Result<agentive::RunnerResult, String>> + Send + 'a>,
+    Box<dyn std::future::Future<Output = Result<agentive::RunnerResult, RunError>> + Send + 'a>,
 > {
     Box::pin(async move {
         let project_workspace_tools_enabled = agent_id.eq_ignore_ascii_case("writer");
-        let tool_defs = tools::all_tools(
-            web_access.search_enabled,
-            project_workspace_tools_enabled && mutation_tools_enabled,
-            mutation_tools_enabled,
-        );
+        let tool_defs = run_tools(agent_id, web_access, mutation_tools_enabled);
         let tool_count = tool_defs.len();
         let packed_context_items =
             build_context_items(project_root, &messages, context_items.clone());
         let context_packing = context_packing_for(&packed_context_items);
+        if let Some(context_tokens) = context_tokens {
+            let context_bytes =
+                packed_context_bytes(&packed_context_items, context_packing.as_ref());
+            if let Err(exceeded) =
+                check_context_fits(&tool_defs, &messages, context_bytes, context_tokens)
+            {
+                log::warn!(
+                    "[agent] request exceeds reported context (depth={}, needed={} tokens, limit={} tokens)",
+                    depth,
+                    exceeded.needed,
+                    exceeded.limit
+                );
+                emit(AgentEvent::Error {
+                    message: exceeded.to_string(),
+                });
+                return Err(RunError::ContextExceeded(exceeded));
+            }
+        }
+        // History from earlier turns can carry tool results that predate the
+        // per-result budget; cap them before the first provider call.
+        let mut messages = messages;
+        let history_chars = agentive::context::estimate_chars(&messages);
+        let truncated_results = truncate_history_tool_results(&mut messages, TOOL_RESULT_MAX_CHARS);
         let starting_chars = agentive::context::estimate_chars(&messages);
+        if truncated_results > 0 {
+            log::info!(
+                "[agent] truncated {} oversized tool results in history (chars={} -> {})",
+                truncated_results,
+                history_chars,
+                starting_chars
+            );
+        }
 
         log::info!(
             "[agent] starting run (depth={}, agent={}, {} messages, chars={}, budget={}chars, tools={}, context_items={}, vision={}, web_search={}, project_workspace_tools={}, mutation_tools={})",
@@ -450,6 +498,7 @@ fn run_inner<'a>(
                     web_search_enabled,
                     mutation_tools_enabled_for_tools,
                     max_tool_rounds,
+                    context_tokens,
                     context_items,
                     context_store,
                     steering,
@@ -532,7 +581,7 @@ fn run_inner<'a>(
                     emit(AgentEvent::Error {
                         message: friendly_context_error(&err),
                     });
-                    return Err(format!("Agent error: {}", friendly_context_error(&err)));
+                    return Err(format!("Agent error: {}", friendly_context_error(&err)).into());
                 }
 
                 emit(AgentEvent::Status {
@@ -622,6 +671,7 @@ fn run_inner<'a>(
                             web_search_enabled,
                             mutation_tools_enabled,
                             max_tool_rounds,
+                            context_tokens,
                             context_items,
                             context_store,
                             steering,
@@ -700,7 +750,7 @@ fn run_inner<'a>(
                 emit(AgentEvent::Error {
                     message: message.clone(),
                 });
-                return Err(message);
+                return Err(message.into());
             }
         };
 
@@ -718,6 +768,100 @@ fn run_inner<'a>(
     })
 }
 
+/// Why a run failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RunError {
+    /// The request can't fit the model context; nothing was sent.
+    ContextExceeded(ContextExceeded),
+    /// Any other failure, already phrased for the user.
+    Failed(String),
+}
+
+impl std::fmt::Display for RunError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RunError::ContextExceeded(exceeded) => exceeded.fmt(f),
+            RunError::Failed(message) => f.write_str(message),
+        }
+    }
+}
+
+impl From<String> for RunError {
+    fn from(message: String) -> Self {
+        RunError::Failed(message)
+    }
+}
+
+/// A request too large for the model even after history is trimmed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContextExceeded {
+    /// Estimated tokens the request needs.
+    pub needed: usize,
+    /// The model's reported context window in tokens.
+    pub limit: usize,
+}
+
+impl std::fmt::Display for ContextExceeded {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "This request needs about {} tokens for tool definitions, instructions, and the latest message, but the selected model's context is {} tokens. Shorten the message or instructions, or pick a model with a larger context window.",
+            self.needed, self.limit
+        )
+    }
+}
+
+/// Check a request against the model's context window before any provider
+/// call, so an oversized request fails with a clear message instead of an
+/// opaque 400.
+///
+/// Only the part trimming can't drop is counted: the tool schemas, the
+/// system/developer prompts, the latest message, and `context_bytes` of
+/// packed context items. Older history is left to agentive's automatic
+/// trimming.
+pub fn check_context_fits(
+    tools: &[agentive::Tool],
+    messages: &[ChatMessage],
+    context_bytes: usize,
+    context_tokens: usize,
+) -> Result<(), ContextExceeded> {
+    let is_prompt = |message: &ChatMessage| matches!(message.role.as_str(), "system" | "developer");
+    let mut fixed: Vec<ChatMessage> = messages.iter().filter(|m| is_prompt(m)).cloned().collect();
+    if let Some(latest) = messages.last().filter(|m| !is_prompt(m)) {
+        fixed.push(latest.clone());
+    }
+    let tool_chars = serde_json::to_string(tools).map_or(0, |json| json.len());
+    let chars = tool_chars + agentive::context::estimate_chars(&fixed) + context_bytes;
+    let needed = chars.div_ceil(CONTEXT_CHARS_PER_TOKEN);
+    if needed > context_tokens {
+        return Err(ContextExceeded {
+            needed,
+            limit: context_tokens,
+        });
+    }
+    Ok(())
+}
+
+/// The most agentive can pack from `items` under `packing`.
+fn packed_context_bytes(
+    items: &[agentive::ContextItem],
+    packing: Option<&agentive::ContextPackingConfig>,
+) -> usize {
+    let Some(packing) = packing else {
+        return 0;
+    };
+    let item_bytes: usize = items
+        .iter()
+        .map(|item| {
+            item.content
+                .as_ref()
+                .map_or(0, String::len)
+                .min(packing.max_item_preview_bytes)
+        })
+        .sum();
+    item_bytes.min(packing.total_budget_bytes)
+}
+
 fn forced_retry_budget(estimated_chars: usize, provider_budget: usize) -> usize {
     let reduced_provider_budget = provider_budget * CONTEXT_FAILURE_RETRY_FRACTION_NUMERATOR
         / CONTEXT_FAILURE_RETRY_FRACTION_DENOMINATOR;
@@ -913,6 +1057,27 @@ fn tool_result_budget() -> agentive::ToolResultBudget {
     }
 }
 
+/// Cut any tool-result message longer than `max_chars`, leaving the head and a
+/// `[truncated N chars]` marker. Returns how many results were truncated.
+fn truncate_history_tool_results(messages: &mut [ChatMessage], max_chars: usize) -> usize {
+    let mut truncated = 0;
+    for message in messages.iter_mut().filter(|message| message.role == "tool") {
+        let Some(agentive::types::MessageContent::Text(text)) = message.content.as_mut() else {
+            continue;
+        };
+        let total = text.chars().count();
+        if total <= max_chars {
+            continue;
+        }
+        // Reserve room for the marker so the result stays within the cap.
+        let keep = max_chars.saturating_sub(32);
+        let head: String = text.chars().take(keep).collect();
+        *text = format!("{head}\n[truncated {} chars]", total - keep);
+        truncated += 1;
+    }
+    truncated
+}
+
 fn trim_history_to_budget(messages: &mut Vec<ChatMessage>, max_chars: usize) -> usize {
     if agentive::context::estimate_chars(messages) <= max_chars {
         return 0;
@@ -1170,6 +1335,7 @@ fn exec_delegation(
     web_search_enabled: bool,
     mutation_tools_enabled: bool,
     max_tool_rounds: usize,
+    context_tokens: Option<usize>,
     context_items: Vec<agentive::ContextItem>,
     agent_state: Option<AgentStateStore>,
     steering: agentive::Steering,
@@ -1229,6 +1395,15 @@ fn exec_delegation(
         let sub_context_items =
             build_context_items(Path::new(&project_root), &sub_messages, context_items);
         let sub_context_packing = context_packing_for(&sub_context_items);
+        if let Some(context_tokens) = context_tokens {
+            let context_bytes =
+                packed_context_bytes(&sub_context_items, sub_context_packing.as_ref());
+            if let Err(exceeded) =
+                check_context_fits(&tools, &sub_messages, context_bytes, context_tokens)
+            {
+                return Ok(agentive::ToolOutput::from(format!("Error: {exceeded}")));
+            }
+        }
 
         emit(AgentEvent::AgentStart {
             agent_id: agent_id.clone(),
@@ -1296,6 +1471,7 @@ fn exec_delegation(
                     web_search_enabled,
                     mutation_tools_enabled,
                     max_tool_rounds,
+                    context_tokens,
                     context_items,
                     context_store,
                     steering,
@@ -1576,6 +1752,26 @@ mod tests {
         assert_eq!(retry_items[0].id, "project-reference:planning-notes");
     }
 
+    #[test]
+    fn oversized_history_tool_results_are_truncated_below_cap() {
+        let mut messages = vec![
+            ChatMessage::user("Read the big file"),
+            ChatMessage::tool_result("call-big", &"z".repeat(50_000)),
+            ChatMessage::tool_result("call-small", "ok"),
+        ];
+
+        let truncated = truncate_history_tool_results(&mut messages, TOOL_RESULT_MAX_CHARS);
+
+        assert_eq!(truncated, 1);
+        let big = messages[1].text().unwrap();
+        assert!(big.chars().count() <= TOOL_RESULT_MAX_CHARS);
+        assert!(big.ends_with(&format!(
+            "[truncated {} chars]",
+            50_000 - (TOOL_RESULT_MAX_CHARS - 32)
+        )));
+        assert_eq!(messages[2].text(), Some("ok"));
+    }
+
     #[test]
     fn history_trim_keeps_tool_results_with_their_tool_call() {
         let tool_call = agentive::ToolCall {
@@ -1612,6 +1808,58 @@ mod tests {
             .is_some_and(|text| text.starts_with("Current request")));
     }
 
+    #[test]
+    fn context_check_counts_tools_prompts_and_latest_message() {
+        let tools = run_tools(
+            "writer",
+            &WebAccessConfig {
+                search_enabled: false,
+            },
+            true,
+        );
+        let tool_chars = serde_json::to_string(&tools).unwrap().len();
+        let history = "h".repeat(200_000);
+        let messages = vec![
+            ChatMessage::system("System instructions"),
+            ChatMessage::user(&history),
+            ChatMessage::assistant("Done"),
+            ChatMessage::user("Now tighten row 2"),
+        ];
+
+        // Older history is trimmable, so it doesn't count toward the check.
+        let fixed = [messages[0].clone(), messages[3].clone()];
+        let needed = (tool_chars + agentive::context::estimate_chars(&fixed))
+            .div_ceil(CONTEXT_CHARS_PER_TOKEN);
+        assert_eq!(check_context_fits(&tools, &messages, 0, needed), Ok(()));
+        assert_eq!(
+            check_context_fits(&tools, &messages, 0, needed - 1),
+            Err(ContextExceeded {
+                needed,
+                limit: needed - 1
+            })
+        );
+    }
+
+    #[test]
+    fn context_check_rejects_oversized_latest_message() {
+        let tools = run_tools(
+            "writer",
+            &WebAccessConfig {
+                search_enabled: false,
+            },
+            true,
+        );
+        let messages = vec![
+            ChatMessage::system("System instructions"),
+            ChatMessage::user(&"p".repeat(1_600_000)),
+        ];
+
+        let err = check_context_fits(&tools, &messages, 0, 384_000).unwrap_err();
+        assert!(err.needed > 400_000);
+        assert_eq!(err.limit, 384_000);
+        assert!(err.to_string().contains("384000 tokens"));
+    }
+
     #[test]
     fn matches_ref_title_case_insensitive() {
         assert!(matches_ref("introduction", "intro.sk", "Introduction"));
@@ -1791,6 +2039,7 @@ mod tests {
             },
             true,
             DEFAULT_MAX_TOOL_ROUNDS,
+            None,
             Vec::new(),
             Some(run_id.clone()),
             Some(store.clone()),
@@ -1822,6 +2071,63 @@ mod tests {
         assert_eq!(event_count(store.db_path(), &run_id, "turn_completed"), 1);
     }
 
+    #[tokio::test]
+    async fn runner_rejects_requests_whose_packed_context_exceeds_the_limit() {
+        let project = tempfile::tempdir().unwrap();
+        fs::write(
+            project.path().join("planning-notes.md"),
+            "Reference detail. ".repeat(1_000),
+        )
+        .unwrap();
+        let web_access = WebAccessConfig {
+            search_enabled: false,
+        };
+        let messages = vec![
+            ChatMessage::system("System instructions"),
+            ChatMessage::user("Use @planning-notes as context."),
+        ];
+        let tools = run_tools("writer", &web_access, true);
+        let without_items = (serde_json::to_string(&tools).unwrap().len()
+            + agentive::context::estimate_chars(&messages))
+        .div_ceil(CONTEXT_CHARS_PER_TOKEN);
+        // Room for the prompt and message, but not the packed reference.
+        let limit = without_items + 100;
+        let provider = Arc::new(HarnessProvider::new(3_000));
+
+        let result = run_inner(
+            provider.clone(),
+            None,
+            None,
+            messages,
+            project.path(),
+            project.path(),
+            "writer",
+            &HashMap::new(),
+            &agentive::Steering::new(),
+            0,
+            &VisionConfig { enabled: false },
+            &web_access,
+            true,
+            DEFAULT_MAX_TOOL_ROUNDS,
+            Some(limit),
+            Vec::new(),
+            None,
+            None,
+            agentive::CancellationToken::new(),
+            Arc::new(|_| {}),
+        )
+        .await;
+
+        match result {
+            Err(RunError::ContextExceeded(exceeded)) => {
+                assert_eq!(exceeded.limit, limit);
+                assert!(exceeded.needed > limit);
+            }
+            other => panic!("expected ContextExceeded, got {other:?}"),
+        }
+        assert_eq!(provider.calls.load(Ordering::SeqCst), 0);
+    }
+
     #[tokio::test]
     async fn runner_passes_cancellation_to_blocking_provider() {
         let project = tempfile::tempdir().unwrap();
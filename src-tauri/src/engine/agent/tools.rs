@@ -235,6 +235,7 @@ fn mutation_tool_names() -> &'static [&'static str] {
         "write_note",
         "write_sketch",
         "update_planning_row",
+        "write_sketch_row",
         "set_row_visual",
         "apply_row_visual_nudge",
         "apply_row_visual_command",
@@ -362,6 +363,29 @@ pub fn all_tools(
                 "required": ["path", "row_number"]
             }),
         ),
+        Tool::function(
+            "write_sketch_row",
+            "Insert a new planning row at row_number, or replace the whole row at row_number. Use mode \"insert\" to add a row (row_number may be one past the last row to append) and mode \"replace\" to overwrite every cell of an existing row. Returns the sketch's new row count.",
+            json!({
+                "type": "object",
+                "properties": {
+                    "path": { "type": "string", "description": "Relative path exactly as returned by list_project_files" },
+                    "row_number": { "type": "integer", "minimum": 1, "description": "1-based planning row number shown in the sketch table, where 1 is the first row" },
+                    "mode": { "type": "string", "enum": ["insert", "replace"], "description": "insert shifts later rows down; replace overwrites the existing row" },
+                    "row": {
+                        "type": "object",
+                        "properties": {
+                            "time": { "type": "string", "description": "Duration (e.g. '~30s', '1:00')" },
+                            "narrative": { "type": "string", "description": "Voiceover/narration bullets" },
+                            "demo_actions": { "type": "string", "description": "On-screen action bullets" },
+                            "screenshot": { "type": "string", "description": "Optional path to a screenshot image" }
+                        },
+                        "required": ["time", "narrative", "demo_actions"]
+                    }
+                },
+                "required": ["path", "row_number", "mode", "row"]
+            }),
+        ),
         Tool::function(
             "set_row_visual",
             "Set an animated framing visual on a planning row using the Elucim document format. Prefer canonical documents with version \"2.0\", scene, and elements keyed by stable semantic IDs; legacy v1 is accepted and migrated. Add lightweight intent metadata to important elements, e.g. intent: { role: 'title'|'subtitle'|'hero'|'step'|'connector'|'label'|'container'|'decoration', importance: 'primary'|'secondary'|'supporting'|'decorative' }. Use CutReady semantic tokens for theme integration: $background, $title, $subtitle, $foreground, $muted, $surface, $border, $accent, $secondary, $tertiary, $success, $warning, $error. Avoid hardcoded cyan/purple for routine emphasis. Prefer polished slide density: roughly 20-40 flattened nodes, 3-5 main objects/steps, minimal labels, and one hero visual metaphor. Avoid token strips, tiny grids, repeated chips, and probability worksheets unless essential. Use valid Elucim fields in element props: text fill (not color) and rect rx (not radius). Put animation in timelines with keyframes, not legacy fadeIn/draw/fadeOut props. Group nodes are useful for transforms but do not make a crowded slide simpler. Auto-validates structure and auto-critiques layout/readability before saving. Returns validation or critique errors if the visual has issues — fix them and call again. Pass null to remove a visual.",
@@ -858,12 +882,17 @@ pub fn execute_tool(
                 }
             }
             "read_note" => exec_read_note(project_root, &args, vision_enabled),
-            "write_note" => agentive::ToolOutput::from(exec_write_note(project_root, &args)),
+            "write_note" => {
+                agentive::ToolOutput::from(exec_write_note(repo_root, project_root, &args))
+            }
             "read_sketch" => exec_read_sketch(project_root, &args, vision_enabled),
             "write_sketch" => agentive::ToolOutput::from(exec_write_sketch(project_root, &args)),
             "update_planning_row" => {
                 agentive::ToolOutput::from(exec_update_planning_row(project_root, &args))
             }
+            "write_sketch_row" => {
+                agentive::ToolOutput::from(exec_write_sketch_row(project_root, &args))
+            }
             "set_row_visual" => {
                 agentive::ToolOutput::from(exec_set_row_visual(project_root, &args))
             }
@@ -1028,10 +1057,26 @@ pub fn decorate_tool_output(
     output.with_metadata(resources, vec![verification], Vec::new())
 }
 
-fn is_tool_error(result_text: &str) -> bool {
+pub(crate) fn is_tool_error(result_text: &str) -> bool {
     result_text.starts_with("Error:") || result_text.starts_with("Validation failed")
 }
 
+/// Kind of project document a mutating tool writes to its `path` argument.
+pub(crate) fn written_document_kind(tool_name: &str) -> Option<&'static str> {
+    match tool_name {
+        "write_note" => Some("note"),
+        "write_sketch"
+        | "update_planning_row"
+        | "write_sketch_row"
+        | "set_row_visual"
+        | "apply_row_visual_nudge"
+        | "apply_row_visual_command"
+        | "design_plan" => Some("sketch"),
+        "write_storyboard" => Some("storyboard"),
+        _ => None,
+    }
+}
+
 fn touched_resources_for_tool(tool_name: &str, args: &Value) -> Vec<agentive::TouchedResource> {
     let mut resources = Vec::new();
     let operation = match tool_name {
@@ -1043,6 +1088,7 @@ fn touched_resources_for_tool(tool_name: &str, args: &Value) -> Vec<agentive::To
         }
         "create_visual" => agentive::ResourceOperation::Create,
         "update_planning_row"
+        | "write_sketch_row"
         | "set_row_visual"
         | "review_row_visual"
         | "apply_row_visual_nudge"
@@ -1067,6 +1113,7 @@ fn touched_resources_for_tool(tool_name: &str, args: &Value) -> Vec<agentive::To
         "read_sketch"
         | "write_sketch"
         | "update_planning_row"
+        | "write_sketch_row"
         | "set_row_visual"
         | "review_row_visual"
         | "apply_row_visual_nudge"
@@ -1771,6 +1818,9 @@ fn paths_equivalent(left: &Path, right: &Path) -> bool {
     left == right
 }
 
+/// Upper bound on a single web search request so a slow provider can't stall the run.
+const SEARCH_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(15);
+
 pub async fn exec_search_web(args: &Value) -> Result<String, String> {
     let query = args
         .get("query")
@@ -1788,7 +1838,10 @@ pub async fn exec_search_web(args: &Value) -> Result<String, String> {
         "https://duckduckgo.com/html/?q={}",
         urlencoding::encode(query)
     );
-    let html = reqwest::Client::new()
+    let html = reqwest::Client::builder()
+        .timeout(SEARCH_TIMEOUT)
+        .build()
+        .map_err(|e| format!("Search client setup failed: {e}"))?
         .get(url)
         .header(
             reqwest::header::USER_AGENT,
@@ -2130,6 +2183,11 @@ fn exec_read_sketch(root: &Path, args: &Value, vision_enabled: bool) -> agentive
     match project::read_sketch(&path) {
         Ok(sketch) => {
             let mut out = format!("# {}\n\n", sketch.title);
+            // Rich-text descriptions are flattened to plain text to keep context small.
+            let description = crate::engine::export::description_text(&sketch.description);
+            if !description.is_empty() {
+                out.push_str(&format!("{description}\n\n"));
+            }
             let mut image_parts: Vec<ContentPart> = Vec::new();
             for (i, row) in sketch.rows.iter().enumerate() {
                 let screenshot_line = match &row.screenshot {
@@ -2391,6 +2449,88 @@ fn exec_update_planning_row(root: &Path, args: &Value) -> String {
     }
 }
 
+fn exec_write_sketch_row(root: &Path, args: &Value) -> String {
+    let path = match args.get("path").and_then(|v| v.as_str()) {
+        Some(p) => resolve_path(root, p),
+        None => {
+            let listing = exec_list_project_files(root, &Value::Null);
+            return format!(
+                "Error: missing 'path' argument. Call write_sketch_row with a path from the list below.\n\n{listing}"
+            );
+        }
+    };
+    let insert = match args.get("mode").and_then(Value::as_str) {
+        Some("insert") => true,
+        Some("replace") => false,
+        _ => return "Error: 'mode' must be \"insert\" or \"replace\"".into(),
+    };
+    let Some(row_args) = args.get("row").filter(|row| row.is_object()) else {
+        return "Error: missing 'row' object with time, narrative, and demo_actions".into();
+    };
+    let mut sketch = match project::read_sketch(&path) {
+        Ok(s) => s,
+        Err(e) => return format!("Error reading sketch: {e}"),
+    };
+    if sketch.locked {
+        return "Error: This sketch is locked. Unlock it before editing with AI.".into();
+    }
+
+    // Inserting may append one past the last row; replacing needs an existing row.
+    let row_count = sketch.rows.len();
+    let index = match parse_row_target(args, row_count + usize::from(insert)) {
+        Ok(index) => index,
+        Err(e) => return e,
+    };
+    if !insert {
+        let existing = &sketch.rows[index];
+        if existing.locked || existing.locks.any() {
+            return format!(
+                "Error: Planning row {} is locked. Unlock it before replacing it with AI.",
+                index + 1
+            );
+        }
+    }
+
+    let mut row = PlanningRow::new();
+    row.time = row_args
+        .get("time")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .into();
+    row.narrative = row_args
+        .get("narrative")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .into();
+    row.demo_actions = row_args
+        .get("demo_actions")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .into();
+    row.screenshot = row_args
+        .get("screenshot")
+        .and_then(Value::as_str)
+        .filter(|s| !s.is_empty())
+        .map(Into::into);
+
+    if insert {
+        sketch.rows.insert(index, row);
+    } else {
+        sketch.rows[index] = row;
+    }
+
+    match project::write_sketch(&sketch, &path, root) {
+        Ok(()) => format!(
+            "{} row {} in {} (sketch now has {} rows)",
+            if insert { "Inserted" } else { "Replaced" },
+            index + 1,
+            path.display(),
+            sketch.rows.len()
+        ),
+        Err(e) => format!("Error writing sketch: {e}"),
+    }
+}
+
 fn format_row_summary(row: Option<&PlanningRow>) -> String {
     match row {
         Some(row) => format!(
@@ -4395,7 +4535,7 @@ fn validate_dsl_doc(visual: &Value, errors: &mut Vec<String>) {
     }
 }
 
-fn exec_write_note(root: &Path, args: &Value) -> String {
+fn exec_write_note(repo_root: &Path, root: &Path, args: &Value) -> String {
     let rel = match args.get("path").and_then(|v| v.as_str()) {
         Some(p) => p,
         None => return "Error: missing 'path' argument (e.g. 'my-note.md')".into(),
@@ -4430,7 +4570,8 @@ fn exec_write_note(root: &Path, args: &Value) -> String {
     }
 
     let created = !path.exists();
-    match project::write_note(&path, content) {
+    let normalize_eol = project::repo_normalizes_line_endings(repo_root);
+    match project::write_note(&path, content, normalize_eol) {
         Ok(()) => {
             if created {
                 format!("Created note '{safe_rel}'")
@@ -5247,6 +5388,7 @@ mod tests {
 
         for name in [
             "update_planning_row",
+            "write_sketch_row",
             "set_row_visual",
             "review_row_visual",
             "apply_row_visual_nudge",
@@ -5436,6 +5578,7 @@ mod tests {
         project::write_manifest(
             repo_root,
             &crate::models::script::ProjectManifest {
+                schema_version: crate::models::script::MANIFEST_SCHEMA_VERSION,
                 projects: vec![crate::models::script::ProjectEntry {
                     path: "source".into(),
                     name: "Source".into(),
@@ -5480,6 +5623,7 @@ mod tests {
         project::write_manifest(
             repo_root,
             &crate::models::script::ProjectManifest {
+                schema_version: crate::models::script::MANIFEST_SCHEMA_VERSION,
                 projects: vec![
                     crate::models::script::ProjectEntry {
                         path: "source".into(),
@@ -5545,6 +5689,7 @@ mod tests {
         project::write_manifest(
             repo_root,
             &crate::models::script::ProjectManifest {
+                schema_version: crate::models::script::MANIFEST_SCHEMA_VERSION,
                 projects: vec![
                     crate::models::script::ProjectEntry {
                         path: "source".into(),
@@ -5564,6 +5709,7 @@ mod tests {
         project::write_note(
             &source_root.join("notes.md"),
             "![Logo](images/logo.png \"Company Logo\")\n![Missing](images/missing.png)",
+            false,
         )
         .unwrap();
 
@@ -5843,6 +5989,102 @@ mod tests {
         assert!(!output.contains("## Row 0"), "{output}");
     }
 
+    #[test]
+    fn write_sketch_row_tool_inserts_and_replaces() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+        let rel = "demo.sk";
+        let mut existing = PlanningRow::new();
+        existing.narrative = "First".into();
+        write_test_sketch(root, rel, existing);
+
+        let inserted = exec_write_sketch_row(
+            root,
+            &json!({
+                "path": rel,
+                "row_number": 2,
+                "mode": "insert",
+                "row": { "time": "0:10", "narrative": "Second", "demo_actions": "Click" }
+            }),
+        );
+        assert!(inserted.contains("now has 2 rows"), "{inserted}");
+
+        let replaced = exec_write_sketch_row(
+            root,
+            &json!({
+                "path": rel,
+                "row_number": 1,
+                "mode": "replace",
+                "row": { "time": "0:00", "narrative": "Opening", "demo_actions": "" }
+            }),
+        );
+        assert!(replaced.contains("now has 2 rows"), "{replaced}");
+
+        let out_of_range = exec_write_sketch_row(
+            root,
+            &json!({
+                "path": rel,
+                "row_number": 3,
+                "mode": "replace",
+                "row": { "time": "", "narrative": "", "demo_actions": "" }
+            }),
+        );
+        assert!(out_of_range.starts_with("Error:"), "{out_of_range}");
+
+        let saved = project::read_sketch(&root.join(rel)).unwrap();
+        let narratives: Vec<&str> = saved
+            .rows
+            .iter()
+            .map(|row| row.narrative.as_str())
+            .collect();
+        assert_eq!(narratives, vec!["Opening", "Second"]);
+        assert_eq!(saved.rows[1].demo_actions, "Click");
+    }
+
+    #[test]
+    fn read_sketch_tool_renders_stable_text() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+        let rel = "demo.sk";
+        let mut sketch = Sketch::new("Intro");
+        sketch.description = json!({
+            "type": "doc",
+            "content": [{
+                "type": "paragraph",
+                "content": [
+                    { "type": "text", "text": "Show the" },
+                    { "type": "text", "text": "dashboard." }
+                ]
+            }]
+        });
+        let mut first = PlanningRow::new();
+        first.time = "0:00".into();
+        first.narrative = "Welcome".into();
+        first.demo_actions = "Click Start".into();
+        let mut second = PlanningRow::new();
+        second.time = "0:10".into();
+        second.narrative = "Wrap up".into();
+        second.demo_actions = "Close".into();
+        second.locked = true;
+        sketch.rows = vec![first, second];
+        project::write_sketch(&sketch, &root.join(rel), root).unwrap();
+
+        let output = tool_output_text(exec_read_sketch(root, &json!({ "path": rel }), false));
+
+        assert_eq!(
+            output,
+            "# Intro\n\n\
+             Show the dashboard.\n\n\
+             ## Row 1 [0:00]\n\
+             **Narrative:** Welcome\n\
+             **Actions:** Click Start\n\n\
+             ## Row 2 [0:10]\n\
+             **Locked:** entire row\n\
+             **Narrative:** Wrap up\n\
+             **Actions:** Close\n\n"
+        );
+    }
+
     #[test]
     fn set_row_visual_tool_rejects_locked_media_cell() {
         let tmp = TempDir::new().unwrap();
@@ -6453,6 +6695,7 @@ mod tests {
         project::set_note_lock(root, "notes/a.md", true).unwrap();
 
         let result = exec_write_note(
+            root,
             root,
             &json!({
                 "path": "notes/a.md",
@@ -6465,6 +6708,28 @@ mod tests {
         assert_eq!(std::fs::read_to_string(note).unwrap(), "Keep this note");
     }
 
+    #[test]
+    fn write_note_tool_follows_the_workspace_line_ending_setting() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+        let project = root.join("demo");
+        std::fs::create_dir_all(&project).unwrap();
+        project::write_repo_settings(root, &json!({ "workspaceNormalizeLineEndings": true }))
+            .unwrap();
+
+        let result = exec_write_note(
+            root,
+            &project,
+            &json!({ "path": "notes/a.md", "content": "# Title\r\nBody\r\n" }),
+        );
+
+        assert_eq!(result, "Created note 'notes/a.md'");
+        assert_eq!(
+            std::fs::read_to_string(project.join("notes/a.md")).unwrap(),
+            "# Title\nBody\n"
+        );
+    }
+
     #[test]
     fn critique_catches_tiny_fonts() {
         let visual = json!({
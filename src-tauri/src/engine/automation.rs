@@ -1,4 +1,346 @@
 //! Automation engine — replay actions via Playwright sidecar + windows-rs UIA.
 //!
-//! Not yet implemented. Will execute Action sequences against browser and native
-//! app targets, with self-healing via the agent engine on failure.
+//! Browser actions replay through the Playwright sidecar, resolving each
+//! action's selectors in priority order. When a provider is supplied, a
+//! browser step that fails is retried once with selectors proposed by
+//! [`healing::heal`]. Native (UIA) replay is not yet implemented; those steps
+//! are reported as failures rather than aborting.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde_json::json;
+
+use crate::engine::agent::healing;
+use crate::engine::agent::llm::Provider;
+use crate::engine::interaction;
+use crate::models::action::{Action, SelectorStrategy};
+use crate::models::session::RecordedSession;
+use crate::util::sidecar::SidecarClient;
+
+/// How often `BrowserWaitForElement` re-checks its selectors.
+const WAIT_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Outcome of replaying a single captured action.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ReplayStep {
+    /// Position of the action in the session.
+    pub index: usize,
+    /// The action's variant name, e.g. `BrowserClick`.
+    pub action_type: String,
+    pub success: bool,
+    /// Which of the action's selector strategies resolved, if any were used.
+    /// After healing this indexes `healed_selectors` instead.
+    pub selector_index: Option<usize>,
+    pub error: Option<String>,
+    /// Replacement selectors that made a failed step succeed.
+    pub healed_selectors: Option<Vec<SelectorStrategy>>,
+}
+
+/// Per-step results of replaying a recorded session.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ReplayReport {
+    pub session_id: uuid::Uuid,
+    pub steps: Vec<ReplayStep>,
+    pub succeeded: usize,
+    pub failed: usize,
+}
+
+/// Replay every action of `session` against the sidecar's active page.
+///
+/// A failed step is recorded and replay continues, so the report shows every
+/// selector that no longer resolves. With a `healer`, failed browser steps are
+/// first retried with healed selectors. `on_step` is called after each step.
+pub async fn replay(
+    sidecar: &impl SidecarClient,
+    session: &RecordedSession,
+    healer: Option<Arc<dyn Provider + Send + Sync>>,
+    mut on_step: impl FnMut(&ReplayStep),
+) -> anyhow::Result<ReplayReport> {
+    let mut steps = Vec::with_capacity(session.actions.len());
+    for (index, captured) in session.actions.iter().enumerate() {
+        let mut result = replay_action(sidecar, &captured.action).await;
+        let mut healed_selectors = None;
+        if let (Err(_), Some(provider)) = (&result, &healer) {
+            if let Some((healed, retried)) =
+                heal_and_retry(sidecar, &captured.action, provider).await
+            {
+                healed_selectors = Some(healed);
+                result = Ok(retried);
+            }
+        }
+        let step = ReplayStep {
+            index,
+            action_type: action_type(&captured.action),
+            success: result.is_ok(),
+            selector_index: result.as_ref().ok().copied().flatten(),
+            error: result.err(),
+            healed_selectors,
+        };
+        on_step(&step);
+        steps.push(step);
+    }
+
+    let succeeded = steps.iter().filter(|step| step.success).count();
+    Ok(ReplayReport {
+        session_id: session.id,
+        failed: steps.len() - succeeded,
+        succeeded,
+        steps,
+    })
+}
+
+/// Ask the healer for replacement selectors and replay `action` with them.
+///
+/// Returns the healed selectors and the retried step's selector index, or
+/// `None` when nothing healed or the retry failed as well.
+async fn heal_and_retry(
+    sidecar: &impl SidecarClient,
+    action: &Action,
+    provider: &Arc<dyn Provider + Send + Sync>,
+) -> Option<(Vec<SelectorStrategy>, Option<usize>)> {
+    // The healer snapshots the browser page, so native steps cannot heal.
+    if !action_type(action).starts_with("Browser") {
+        return None;
+    }
+    let healed = match healing::heal(sidecar, action, provider.clone()).await {
+        Ok(healed) if !healed.is_empty() => healed,
+        Ok(_) => return None,
+        Err(err) => {
+            log::warn!("[replay] selector healing failed: {err}");
+            return None;
+        }
+    };
+    let mut patched = action.clone();
+    *patched.selectors_mut()? = healed.clone();
+    let retried = replay_action(sidecar, &patched).await.ok()?;
+    Some((healed, retried))
+}
+
+/// Execute one action, returning the index of the selector strategy used.
+async fn replay_action(
+    sidecar: &impl SidecarClient,
+    action: &Action,
+) -> Result<Option<usize>, String> {
+    match action {
+        Action::BrowserNavigate { url } => {
+            perform(sidecar, json!({ "kind": "navigate", "url": url })).await?;
+            Ok(None)
+        }
+        Action::BrowserClick { selectors } => {
+            let (index, selector) = resolve(sidecar, selectors).await?;
+            perform(sidecar, json!({ "kind": "click", "selector": selector })).await?;
+            Ok(Some(index))
+        }
+        Action::BrowserType {
+            selectors,
+            text,
+            clear_first,
+        } => {
+            let (index, selector) = resolve(sidecar, selectors).await?;
+            perform(
+                sidecar,
+                json!({
+                    "kind": "type",
+                    "selector": selector,
+                    "text": text,
+                    "clear_first": clear_first,
+                }),
+            )
+            .await?;
+            Ok(Some(index))
+        }
+        Action::BrowserSelect { selectors, value } => {
+            let (index, selector) = resolve(sidecar, selectors).await?;
+            perform(
+                sidecar,
+                json!({ "kind": "select", "selector": selector, "value": value }),
+            )
+            .await?;
+            Ok(Some(index))
+        }
+        Action::BrowserScroll { direction, amount } => {
+            perform(
+                sidecar,
+                json!({ "kind": "scroll", "direction": direction, "amount": amount }),
+            )
+            .await?;
+            Ok(None)
+        }
+        Action::BrowserWaitForElement {
+            selectors,
+            timeout_ms,
+        } => {
+            let deadline = tokio::time::Instant::now() + Duration::from_millis(*timeout_ms);
+            loop {
+                if let Ok((index, _)) = resolve(sidecar, selectors).await {
+                    return Ok(Some(index));
+                }
+                if tokio::time::Instant::now() >= deadline {
+                    return Err(format!("Element did not appear within {timeout_ms}ms"));
+                }
+                tokio::time::sleep(WAIT_POLL_INTERVAL).await;
+            }
+        }
+        Action::Wait { duration_ms } => {
+            tokio::time::sleep(Duration::from_millis(*duration_ms)).await;
+            Ok(None)
+        }
+        Action::Annotation { .. } | Action::Screenshot { .. } => Ok(None),
+        Action::NativeLaunch { .. }
+        | Action::NativeClick { .. }
+        | Action::NativeType { .. }
+        | Action::NativeSelect { .. }
+        | Action::NativeInvoke { .. } => Err("Native app replay is not supported yet".into()),
+    }
+}
+
+/// Resolve the first selector that matches exactly one element.
+async fn resolve(
+    sidecar: &impl SidecarClient,
+    selectors: &[SelectorStrategy],
+) -> Result<(usize, SelectorStrategy), String> {
+    interaction::first_unique_selector(sidecar, selectors)
+        .await
+        .map_err(|e| e.to_string())?
+        .map(|resolved| (resolved.index, resolved.strategy))
+        .ok_or_else(|| "No selector resolved to a unique element".to_string())
+}
+
+async fn perform(sidecar: &impl SidecarClient, params: serde_json::Value) -> Result<(), String> {
+    sidecar.request("browser.perform", params).await.map(|_| ())
+}
+
+/// The serde tag of an action, e.g. `"BrowserClick"`.
+pub fn action_type(action: &Action) -> String {
+    serde_json::to_value(action)
+        .ok()
+        .and_then(|value| value.get("type")?.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::agent::llm::fake::FakeProvider;
+    use crate::models::action::ActionMetadata;
+    use crate::models::session::{CapturedAction, RecordingMode};
+    use std::sync::Mutex;
+
+    /// Sidecar stub where only `#ok` resolves; records every perform call.
+    #[derive(Default)]
+    struct StubSidecar {
+        performed: Mutex<Vec<serde_json::Value>>,
+    }
+
+    impl SidecarClient for StubSidecar {
+        async fn request(
+            &self,
+            method: &str,
+            params: serde_json::Value,
+        ) -> Result<serde_json::Value, String> {
+            match method {
+                "browser.validateSelector" => {
+                    let count = u64::from(params["value"] == "#ok");
+                    Ok(json!({ "match_count": count }))
+                }
+                "browser.snapshot" => Ok(json!({
+                    "url": "https://example.com",
+                    "html": "<button id=\"ok\">OK</button>",
+                })),
+                "browser.perform" => {
+                    self.performed.lock().unwrap().push(params);
+                    Ok(json!({ "status": "ok" }))
+                }
+                other => Err(format!("unexpected method {other}")),
+            }
+        }
+    }
+
+    fn captured(action: Action) -> CapturedAction {
+        CapturedAction {
+            action,
+            metadata: ActionMetadata {
+                captured_screenshot: None,
+                selector_strategies: vec![],
+                timestamp_ms: 0,
+                confidence: 0.9,
+                context_snapshot: None,
+            },
+            raw_event: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn replay_records_failures_and_continues() {
+        let mut session = RecordedSession::new(RecordingMode::FreeForm);
+        session.actions = vec![
+            captured(Action::BrowserNavigate {
+                url: "https://example.com".into(),
+            }),
+            captured(Action::BrowserClick {
+                selectors: vec![SelectorStrategy::CssSelector("#gone".into())],
+            }),
+            captured(Action::BrowserType {
+                selectors: vec![
+                    SelectorStrategy::CssSelector("#gone".into()),
+                    SelectorStrategy::CssSelector("#ok".into()),
+                ],
+                text: "hello".into(),
+                clear_first: true,
+            }),
+        ];
+        let sidecar = StubSidecar::default();
+        let mut seen = Vec::new();
+
+        let report = replay(&sidecar, &session, None, |step| seen.push(step.index))
+            .await
+            .unwrap();
+
+        assert_eq!(seen, vec![0, 1, 2]);
+        assert_eq!((report.succeeded, report.failed), (2, 1));
+        assert!(!report.steps[1].success);
+        assert_eq!(report.steps[1].action_type, "BrowserClick");
+        assert!(report.steps[1].error.is_some());
+        assert_eq!(report.steps[2].selector_index, Some(1));
+
+        let performed = sidecar.performed.lock().unwrap();
+        assert_eq!(performed.len(), 2);
+        assert_eq!(performed[1]["kind"], "type");
+        assert_eq!(performed[1]["selector"]["value"], "#ok");
+    }
+
+    #[tokio::test]
+    async fn replay_retries_failed_steps_with_healed_selectors() {
+        let mut session = RecordedSession::new(RecordingMode::FreeForm);
+        session.actions = vec![
+            captured(Action::BrowserClick {
+                selectors: vec![SelectorStrategy::CssSelector("#gone".into())],
+            }),
+            captured(Action::NativeClick {
+                selectors: vec![SelectorStrategy::AccessibilityId("save".into())],
+            }),
+        ];
+        let sidecar = StubSidecar::default();
+        let healer: Arc<dyn Provider + Send + Sync> = Arc::new(FakeProvider::new(vec![
+            r##"{"selectors": [{"strategy": "CssSelector", "value": "#ok"}]}"##.into(),
+        ]));
+
+        let report = replay(&sidecar, &session, Some(healer), |_| {})
+            .await
+            .unwrap();
+
+        assert!(report.steps[0].success);
+        assert_eq!(
+            report.steps[0].healed_selectors,
+            Some(vec![SelectorStrategy::CssSelector("#ok".into())])
+        );
+        assert_eq!(report.steps[0].selector_index, Some(0));
+        assert!(!report.steps[1].success);
+        assert!(report.steps[1].healed_selectors.is_none());
+
+        let performed = sidecar.performed.lock().unwrap();
+        assert_eq!(performed.len(), 1);
+        assert_eq!(performed[0]["selector"]["value"], "#ok");
+    }
+}
@@ -0,0 +1,138 @@
+//! Session cleanup passes — strip noise from recorded sessions.
+//!
+//! Each pass takes a session and returns a cleaned copy, leaving the original
+//! untouched so callers decide whether to persist the result.
+
+use crate::models::action::SelectorStrategy;
+use crate::models::session::RecordedSession;
+
+/// Default confidence below which captured actions are considered guesses.
+pub const DEFAULT_CONFIDENCE_THRESHOLD: f32 = 0.3;
+
+/// Drop actions whose recorder confidence is below `threshold`.
+///
+/// Actions exactly at the threshold are kept. Returns the filtered session and
+/// the number of actions removed.
+pub fn filter_low_confidence(
+    session: &RecordedSession,
+    threshold: f32,
+) -> (RecordedSession, usize) {
+    let mut filtered = session.clone();
+    filtered
+        .actions
+        .retain(|captured| captured.metadata.confidence >= threshold);
+    let removed = session.actions.len() - filtered.actions.len();
+    (filtered, removed)
+}
+
+/// Reorder each action's selectors so the most stable candidate comes first.
+///
+/// The sort is stable, so selectors of equal rank keep their recorded order.
+/// Returns the stabilized session and the number of actions reordered.
+pub fn stabilize_selectors(session: &RecordedSession) -> (RecordedSession, usize) {
+    let mut stabilized = session.clone();
+    let mut reordered = 0;
+    for captured in &mut stabilized.actions {
+        let Some(selectors) = captured.action.selectors_mut() else {
+            continue;
+        };
+        let sorted = selectors
+            .windows(2)
+            .all(|pair| selector_stability(&pair[0]) <= selector_stability(&pair[1]));
+        if !sorted {
+            selectors.sort_by_key(selector_stability);
+            reordered += 1;
+        }
+    }
+    (stabilized, reordered)
+}
+
+/// Rank a selector by how well it survives page changes, most stable first.
+/// Follows the order the recorder sidecar prefers when capturing candidates.
+fn selector_stability(selector: &SelectorStrategy) -> u8 {
+    match selector {
+        SelectorStrategy::DataTestId(_) => 0,
+        SelectorStrategy::AccessibilityId(_) => 1,
+        SelectorStrategy::CssSelector(css) if is_id_selector(css) => 2,
+        SelectorStrategy::AccessibilityName(_) => 3,
+        SelectorStrategy::TextContent(_) => 4,
+        SelectorStrategy::CssSelector(_) => 5,
+        SelectorStrategy::UiaTreePath(_) => 6,
+        SelectorStrategy::XPath(_) => 7,
+    }
+}
+
+/// Whether `css` is a lone `#id` selector rather than a structural path.
+fn is_id_selector(css: &str) -> bool {
+    css.strip_prefix('#').is_some_and(|id| {
+        !id.is_empty() && !id.contains(|c: char| c.is_whitespace() || ">+~.[:".contains(c))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::action::{Action, ActionMetadata};
+    use crate::models::session::{CapturedAction, RecordingMode};
+
+    fn wait_with_confidence(confidence: f32) -> CapturedAction {
+        CapturedAction {
+            action: Action::Wait { duration_ms: 100 },
+            metadata: ActionMetadata {
+                captured_screenshot: None,
+                selector_strategies: vec![],
+                timestamp_ms: 0,
+                confidence,
+                context_snapshot: None,
+            },
+            raw_event: None,
+        }
+    }
+
+    #[test]
+    fn filter_low_confidence_keeps_threshold_and_drops_below() {
+        let mut session = RecordedSession::new(RecordingMode::FreeForm);
+        session.actions.push(wait_with_confidence(0.3));
+        session.actions.push(wait_with_confidence(0.29));
+        session.actions.push(wait_with_confidence(0.9));
+
+        let (filtered, removed) = filter_low_confidence(&session, 0.3);
+
+        assert_eq!(removed, 1);
+        assert_eq!(filtered.actions.len(), 2);
+        assert!((filtered.actions[0].metadata.confidence - 0.3).abs() < f32::EPSILON);
+        assert_eq!(session.actions.len(), 3);
+    }
+
+    #[test]
+    fn stabilize_selectors_puts_stable_candidates_first() {
+        let mut session = RecordedSession::new(RecordingMode::FreeForm);
+        let mut brittle = wait_with_confidence(1.0);
+        brittle.action = Action::BrowserClick {
+            selectors: vec![
+                SelectorStrategy::XPath("/html/body/div[3]/button".into()),
+                SelectorStrategy::CssSelector("div > button.primary".into()),
+                SelectorStrategy::CssSelector("#save".into()),
+                SelectorStrategy::DataTestId("save".into()),
+            ],
+        };
+        session.actions.push(brittle);
+        session.actions.push(wait_with_confidence(1.0));
+
+        let (stabilized, reordered) = stabilize_selectors(&session);
+
+        assert_eq!(reordered, 1);
+        assert_eq!(
+            stabilized.actions[0].action,
+            Action::BrowserClick {
+                selectors: vec![
+                    SelectorStrategy::DataTestId("save".into()),
+                    SelectorStrategy::CssSelector("#save".into()),
+                    SelectorStrategy::CssSelector("div > button.primary".into()),
+                    SelectorStrategy::XPath("/html/body/div[3]/button".into()),
+                ],
+            }
+        );
+        assert_eq!(stabilize_selectors(&stabilized).1, 0);
+    }
+}
@@ -124,6 +124,31 @@ impl CutReadyDraftlineAdapter {
         self.workspace.preview_version_file(version, path)
     }
 
+    /// Versions on the current variation that changed `path`, newest first.
+    ///
+    /// A version is included when the file's previewed content differs from
+    /// the save before it, which also covers the file being added or removed.
+    /// Saves where the file is absent on both sides are skipped.
+    pub fn file_history(&self, path: impl AsRef<Path>) -> DraftlineResult<Vec<Version>> {
+        let path = path.as_ref();
+        let versions = self.versions()?;
+        let mut contents = Vec::with_capacity(versions.len());
+        for version in &versions {
+            let file = self.preview_version_file(version.id(), path)?;
+            contents.push(file.map(|file| file.content));
+        }
+
+        Ok(versions
+            .into_iter()
+            .enumerate()
+            .filter(|(index, _)| {
+                let previous = contents.get(index + 1).cloned().flatten();
+                contents[*index] != previous
+            })
+            .map(|(_, version)| version)
+            .collect())
+    }
+
     pub fn create_variation_from(
         &self,
         version: &VersionId,
@@ -382,6 +407,11 @@ mod tests {
         run_git(root, &["init", "--initial-branch=main"]);
     }
 
+    fn adoption_context() -> draftline::tauri_contract::DraftlineCommandContext<'static> {
+        draftline::tauri_contract::DraftlineCommandContext::new()
+            .with_content_policy(cutready_content_policy().unwrap())
+    }
+
     fn configure_identity(root: &Path, name: &str, email: &str) {
         run_git(root, &["config", "user.name", name]);
         run_git(root, &["config", "user.email", email]);
@@ -548,6 +578,26 @@ mod tests {
             .any(|file| file.path.as_path() == Path::new(".cutready/projects.json")));
     }
 
+    #[test]
+    fn file_history_lists_only_versions_that_touched_the_path() {
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path();
+        write(root.join("intro.sk"), r#"{"title":"One"}"#);
+
+        let adapter = CutReadyDraftlineAdapter::open_project(root).unwrap();
+        let added = adapter.save_version("Add intro").unwrap();
+        write(root.join("planning.md"), "# Plan\n");
+        let unrelated = adapter.save_version("Add plan").unwrap();
+        write(root.join("intro.sk"), r#"{"title":"Two"}"#);
+        let updated = adapter.save_version("Update intro").unwrap();
+
+        let history = adapter.file_history("intro.sk").unwrap();
+        let ids: Vec<_> = history.iter().map(|version| version.id()).collect();
+        assert_eq!(ids, vec![updated.id(), added.id()]);
+        assert!(!ids.contains(&unrelated.id()));
+        assert!(adapter.file_history("missing.sk").unwrap().is_empty());
+    }
+
     #[test]
     fn shelf_lifecycle_replaces_git_stash_for_tracked_content() {
         let temp = tempfile::tempdir().unwrap();
@@ -870,6 +920,56 @@ mod tests {
         assert_eq!(changes.files[0].path.as_path(), Path::new("intro.sk"));
     }
 
+    #[test]
+    fn adopt_repo_surfaces_existing_branches_as_timelines() {
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path().join("demo-plans");
+        write(root.join("intro.sk"), r#"{"title":"Intro"}"#);
+        init_legacy_git_repo(&root);
+        configure_identity(&root, "Legacy User", "legacy@example.com");
+        commit_all(&root, "existing plan");
+        run_git(&root, &["branch", "alt-take"]);
+
+        crate::engine::project::adopt_repo(&root, &mut adoption_context()).unwrap();
+
+        let adapter = CutReadyDraftlineAdapter::open_project(&root).unwrap();
+        let names: Vec<_> = adapter
+            .variations()
+            .unwrap()
+            .into_iter()
+            .map(|variation| variation.name)
+            .collect();
+        assert_eq!(names.len(), 2, "got {names:?}");
+        assert!(names.iter().any(|name| name == "main"));
+        assert!(names.iter().any(|name| name == "alt-take"));
+        assert!(!adapter.versions().unwrap().is_empty());
+    }
+
+    #[test]
+    fn adopt_repo_keeps_a_master_default_branch() {
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path().join("old-plans");
+        write(root.join("intro.sk"), r#"{"title":"Intro"}"#);
+        run_git(&root, &["init", "--initial-branch=master"]);
+        configure_identity(&root, "Legacy User", "legacy@example.com");
+        commit_all(&root, "existing plan");
+        run_git(&root, &["branch", "alt-take"]);
+
+        crate::engine::project::adopt_repo(&root, &mut adoption_context()).unwrap();
+
+        let adapter = CutReadyDraftlineAdapter::open_project(&root).unwrap();
+        let names: Vec<_> = adapter
+            .variations()
+            .unwrap()
+            .into_iter()
+            .map(|variation| variation.name)
+            .collect();
+        assert_eq!(names.len(), 2, "got {names:?}");
+        assert!(names.iter().any(|name| name == "master"));
+        assert!(names.iter().all(|name| name != "main"));
+        assert!(!adapter.versions().unwrap().is_empty());
+    }
+
     #[test]
     fn sync_status_reports_incoming_versions_after_fetch() {
         let temp = tempfile::tempdir().unwrap();
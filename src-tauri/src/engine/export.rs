@@ -2083,47 +2083,13 @@ fn title_card_filter(sketch: &Sketch, settings: &SketchVideoExportSettings) -> S
     FfmpegFilterGraph::chain(filters).render()
 }
 
-fn description_text(value: &serde_json::Value) -> String {
-    let mut parts = Vec::new();
-    collect_text_values(value, &mut parts);
-    parts
-        .join(" ")
+pub(crate) fn description_text(value: &serde_json::Value) -> String {
+    crate::util::richtext::to_plain_text(value)
         .split_whitespace()
         .collect::<Vec<_>>()
         .join(" ")
 }
 
-fn collect_text_values(value: &serde_json::Value, parts: &mut Vec<String>) {
-    match value {
-        serde_json::Value::String(text) => {
-            let trimmed = text.trim();
-            if !trimmed.is_empty() {
-                parts.push(trimmed.to_string());
-            }
-        }
-        serde_json::Value::Array(items) => {
-            for item in items {
-                collect_text_values(item, parts);
-            }
-        }
-        serde_json::Value::Object(map) => {
-            if let Some(serde_json::Value::String(text)) = map.get("text") {
-                let trimmed = text.trim();
-                if !trimmed.is_empty() {
-                    parts.push(trimmed.to_string());
-                }
-                return;
-            }
-            for (key, item) in map {
-                if key != "type" {
-                    collect_text_values(item, parts);
-                }
-            }
-        }
-        _ => {}
-    }
-}
-
 fn wrap_text(value: &str, max_chars: usize, max_lines: usize) -> Vec<String> {
     let words = value.split_whitespace().collect::<Vec<_>>();
     if words.is_empty() {
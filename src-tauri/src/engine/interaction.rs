@@ -10,7 +10,10 @@
 
 use std::path::{Path, PathBuf};
 
-use crate::util::sidecar::SidecarManager;
+use crate::engine::cleanup;
+use crate::models::action::SelectorStrategy;
+use crate::models::session::{CapturedAction, RecordedSession, SessionSummary};
+use crate::util::sidecar::{SidecarClient, SidecarManager};
 
 // ── Browser Profile Detection ───────────────────────────────────────────────
 
@@ -191,6 +194,83 @@ pub fn check_browsers_running() -> BrowserRunningStatus {
     }
 }
 
+/// Profiles, running browsers, and the channel the recorder should offer first.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RecorderReadiness {
+    pub profiles: Vec<BrowserProfile>,
+    pub running: BrowserRunningStatus,
+    /// A browser with profiles that isn't open (so its profile isn't locked),
+    /// or `None` when only a fresh browser can be launched.
+    pub recommended_channel: Option<String>,
+    /// Human-readable explanation of the recommendation.
+    pub reason: String,
+}
+
+/// Detect profiles and running browsers together and pick a usable channel.
+pub fn recorder_readiness() -> RecorderReadiness {
+    readiness_from(detect_browser_profiles(), check_browsers_running())
+}
+
+/// Prefer Edge, then Chrome (the sidecar's own fallback order), skipping any
+/// browser whose window is open because its profile directory is locked.
+fn readiness_from(
+    profiles: Vec<BrowserProfile>,
+    running: BrowserRunningStatus,
+) -> RecorderReadiness {
+    let candidates = [
+        ("msedge", "Edge", running.msedge),
+        ("chrome", "Chrome", running.chrome),
+    ];
+    let with_profiles: Vec<_> = candidates
+        .iter()
+        .filter(|(channel, _, _)| profiles.iter().any(|p| p.browser == *channel))
+        .collect();
+
+    let open_names: Vec<&str> = with_profiles
+        .iter()
+        .filter(|(_, _, is_running)| *is_running)
+        .map(|(_, name, _)| *name)
+        .collect();
+    let usable = with_profiles.iter().find(|(_, _, is_running)| !is_running);
+
+    let (recommended_channel, reason) = if with_profiles.is_empty() {
+        (
+            None,
+            "No Edge or Chrome profiles found; a fresh browser will be used".to_string(),
+        )
+    } else {
+        match (usable, open_names.as_slice()) {
+            (Some((channel, name, _)), []) => (Some(channel.to_string()), format!("Using {name}")),
+            (Some((channel, name, _)), open) => (
+                Some(channel.to_string()),
+                format!("{} is open, using {name}", open.join(" and ")),
+            ),
+            (None, open) => (
+                None,
+                format!(
+                    "{} {} open; close {} to use a profile, or use a fresh browser",
+                    open.join(" and "),
+                    if open.len() == 1 { "is" } else { "are" },
+                    if open.len() == 1 { "it" } else { "one" }
+                ),
+            ),
+        }
+    };
+
+    RecorderReadiness {
+        profiles,
+        running,
+        recommended_channel,
+        reason,
+    }
+}
+
+/// Browser channels the sidecar knows how to launch.
+pub const SUPPORTED_BROWSER_CHANNELS: &[&str] = &["msedge", "chrome", "chromium", "firefox"];
+
+/// Channel name for Playwright's Firefox build.
+const FIREFOX_CHANNEL: &str = "firefox";
+
 /// Options for preparing a browser.
 #[derive(Debug, Default)]
 pub struct PrepareBrowserOptions {
@@ -198,11 +278,41 @@ pub struct PrepareBrowserOptions {
     pub user_data_dir: Option<String>,
     /// Profile directory name (e.g., "Default", "Profile 1").
     pub profile_directory: Option<String>,
-    /// Browser channel to use (e.g., "msedge", "chrome").
-    /// Only relevant when launching with a profile.
+    /// Browser channel to use ("msedge", "chrome", "chromium", or "firefox").
+    /// Chromium channels only apply when launching with a profile; "firefox"
+    /// always launches Firefox, in a fresh context.
     pub browser_channel: Option<String>,
 }
 
+impl PrepareBrowserOptions {
+    /// Build the `browser.prepare` params for the sidecar.
+    ///
+    /// Firefox doesn't support Chromium-style persistent-context profiles, so a
+    /// Firefox profile request falls back to a fresh context. The returned flag
+    /// is `true` when that fallback happened.
+    fn sidecar_params(&self) -> (serde_json::Value, bool) {
+        match (
+            &self.user_data_dir,
+            &self.profile_directory,
+            self.browser_channel.as_deref(),
+        ) {
+            (udd, pd, Some(FIREFOX_CHANNEL)) => (
+                serde_json::json!({ "browser_channel": FIREFOX_CHANNEL }),
+                udd.is_some() || pd.is_some(),
+            ),
+            (Some(udd), Some(pd), Some(ch)) => (
+                serde_json::json!({
+                    "user_data_dir": udd,
+                    "profile_directory": pd,
+                    "browser_channel": ch,
+                }),
+                false,
+            ),
+            _ => (serde_json::json!({}), false),
+        }
+    }
+}
+
 /// Resolve the playwright-sidecar directory.
 ///
 /// In development, this is relative to the Cargo manifest (i.e. the project root).
@@ -232,6 +342,8 @@ pub fn resolve_screenshots_dir(
 /// When `options` includes a profile, launches with `launchPersistentContext`
 /// using the user's real browser profile (with extensions, passwords, etc.).
 /// Otherwise tries Edge → Chrome → bundled Chromium in fresh mode.
+/// A "firefox" channel launches Firefox; a requested Firefox profile falls back
+/// to a fresh context and the returned channel reads "firefox (fresh context)".
 ///
 /// Returns the sidecar, event receiver, and which browser channel was used.
 pub async fn prepare_browser(
@@ -242,40 +354,51 @@ pub async fn prepare_browser(
     tokio::sync::mpsc::UnboundedReceiver<crate::models::session::CapturedAction>,
     String,
 )> {
+    if let Some(channel) = options.browser_channel.as_deref() {
+        if !SUPPORTED_BROWSER_CHANNELS.contains(&channel) {
+            anyhow::bail!("Unsupported browser channel: {channel}");
+        }
+    }
+
     let (sidecar, event_rx) = SidecarManager::spawn(sidecar_dir).await?;
 
     sidecar
         .ping()
         .await
-        .map_err(|e| anyhow::anyhow!("Sidecar ping failed: {e}"))?;
-
-    let params = match (
-        &options.user_data_dir,
-        &options.profile_directory,
-        &options.browser_channel,
-    ) {
-        (Some(udd), Some(pd), Some(ch)) => serde_json::json!({
-            "user_data_dir": udd,
-            "profile_directory": pd,
-            "browser_channel": ch,
-        }),
-        _ => serde_json::json!({}),
-    };
+        .map_err(|e| sidecar_failure(&sidecar, format!("Sidecar ping failed: {e}")))?;
+
+    let (params, profile_fallback) = options.sidecar_params();
 
     let result = sidecar
         .request("browser.prepare", params)
         .await
-        .map_err(|e| anyhow::anyhow!("Browser prepare failed: {e}"))?;
+        .map_err(|e| sidecar_failure(&sidecar, format!("Browser prepare failed: {e}")))?;
 
-    let browser_channel = result
+    let mut browser_channel = result
         .get("browser_channel")
         .and_then(|v| v.as_str())
         .unwrap_or("chromium")
         .to_string();
+    if profile_fallback {
+        browser_channel.push_str(" (fresh context)");
+    }
 
     Ok((sidecar, event_rx, browser_channel))
 }
 
+/// Lines of sidecar stderr appended to a prepare failure.
+const STDERR_LINES_IN_ERRORS: usize = 5;
+
+/// Attach the sidecar's last few stderr lines to an error message.
+fn sidecar_failure(sidecar: &SidecarManager, message: String) -> anyhow::Error {
+    let stderr = sidecar.recent_stderr();
+    if stderr.is_empty() {
+        return anyhow::anyhow!(message);
+    }
+    let tail = &stderr[stderr.len().saturating_sub(STDERR_LINES_IN_ERRORS)..];
+    anyhow::anyhow!("{message}\n{}", tail.join("\n"))
+}
+
 /// Start observing the active page in a prepared browser.
 ///
 /// Injects the DOM observer and begins forwarding captured actions.
@@ -313,29 +436,469 @@ pub async fn disconnect_browser(sidecar: &SidecarManager) -> anyhow::Result<()>
     Ok(())
 }
 
+// ── Selector Validation ─────────────────────────────────────────────────────
+
+/// How a recorded selector resolves against the live page.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct SelectorValidation {
+    /// Number of elements the selector matches right now.
+    pub match_count: u64,
+    /// Whether the selector matches exactly one element.
+    pub unique: bool,
+}
+
+impl SelectorValidation {
+    fn from_match_count(match_count: u64) -> Self {
+        Self {
+            match_count,
+            unique: match_count == 1,
+        }
+    }
+}
+
+/// The first selector of an action that resolves to exactly one element.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ResolvedSelector {
+    /// Position of the strategy in the action's selector list.
+    pub index: usize,
+    pub strategy: SelectorStrategy,
+    pub validation: SelectorValidation,
+}
+
+/// Count how many elements a selector strategy matches on the active page.
+///
+/// UIA tree paths target native apps, so they never match in the browser.
+pub async fn validate_selector(
+    sidecar: &impl SidecarClient,
+    strategy: &SelectorStrategy,
+) -> anyhow::Result<SelectorValidation> {
+    if matches!(strategy, SelectorStrategy::UiaTreePath(_)) {
+        return Ok(SelectorValidation::from_match_count(0));
+    }
+
+    let result = sidecar
+        .request("browser.validateSelector", serde_json::to_value(strategy)?)
+        .await
+        .map_err(|e| anyhow::anyhow!("Selector validation failed: {e}"))?;
+
+    let match_count = result
+        .get("match_count")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(0);
+    Ok(SelectorValidation::from_match_count(match_count))
+}
+
+/// Validate an action's selectors in priority order and return the first one
+/// that resolves uniquely, or `None` if every strategy is missing or ambiguous.
+pub async fn first_unique_selector(
+    sidecar: &impl SidecarClient,
+    strategies: &[SelectorStrategy],
+) -> anyhow::Result<Option<ResolvedSelector>> {
+    for (index, strategy) in strategies.iter().enumerate() {
+        let validation = validate_selector(sidecar, strategy).await?;
+        if validation.unique {
+            return Ok(Some(ResolvedSelector {
+                index,
+                strategy: strategy.clone(),
+                validation,
+            }));
+        }
+    }
+    Ok(None)
+}
+
+// ── Element Screenshots ─────────────────────────────────────────────────────
+
+/// Screenshot the element a selector targets into `screenshots_dir`.
+///
+/// The sidecar falls back to a full-page screenshot when the selector doesn't
+/// resolve. Returns the saved file's path relative to `project_root`.
+pub async fn capture_element(
+    sidecar: &impl SidecarClient,
+    selector: &SelectorStrategy,
+    project_root: &Path,
+    screenshots_dir: &Path,
+) -> anyhow::Result<PathBuf> {
+    let output_path = screenshots_dir.join(format!("element_{}.png", uuid::Uuid::new_v4()));
+    let mut params = serde_json::to_value(selector)?;
+    params["output_path"] = output_path.to_string_lossy().into();
+
+    sidecar
+        .request("browser.screenshotElement", params)
+        .await
+        .map_err(|e| anyhow::anyhow!("Element screenshot failed: {e}"))?;
+
+    let relative = output_path
+        .strip_prefix(project_root)
+        .map_err(|_| anyhow::anyhow!("Screenshots folder is outside the project"))?;
+    Ok(relative.to_path_buf())
+}
+
+// ── Capture Deduplication ───────────────────────────────────────────────────
+
+/// Identical actions closer together than this are treated as observer noise.
+///
+/// Kept well below a human double-click interval so deliberate repeats survive.
+pub const DEDUP_WINDOW_MS: u64 = 100;
+
+/// Whether `next` is a duplicate emission of `prev` that should be dropped.
+///
+/// Only structurally equal actions within [`DEDUP_WINDOW_MS`] of each other
+/// are considered duplicates.
+pub fn should_dedup(prev: &CapturedAction, next: &CapturedAction) -> bool {
+    prev.action == next.action
+        && prev
+            .metadata
+            .timestamp_ms
+            .abs_diff(next.metadata.timestamp_ms)
+            <= DEDUP_WINDOW_MS
+}
+
+// ── Idle Watchdog ───────────────────────────────────────────────────────────
+
+/// How long a take may go without a captured action before the user is warned.
+pub const RECORDING_IDLE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(120);
+
+/// Extra idle time after the warning before the take is stopped automatically.
+/// `None` only warns.
+pub const RECORDING_IDLE_AUTO_STOP_GRACE: Option<std::time::Duration> =
+    Some(std::time::Duration::from_secs(60));
+
+/// How often the forwarding task checks for an idle take.
+pub const RECORDING_IDLE_CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Minimum spacing between `recording-progress` events during a take.
+pub const RECORDING_PROGRESS_INTERVAL: std::time::Duration = std::time::Duration::from_millis(100);
+
+/// What the idle watchdog should do for an active take.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdleAction {
+    None,
+    Warn,
+    Stop,
+}
+
+/// Decide the watchdog step for a take that has been idle for `idle_for`.
+/// `warned` is whether this idle stretch was already reported.
+pub fn idle_action(idle_for: std::time::Duration, warned: bool) -> IdleAction {
+    if idle_for < RECORDING_IDLE_TIMEOUT {
+        return IdleAction::None;
+    }
+    if !warned {
+        return IdleAction::Warn;
+    }
+    match RECORDING_IDLE_AUTO_STOP_GRACE {
+        Some(grace) if idle_for >= RECORDING_IDLE_TIMEOUT + grace => IdleAction::Stop,
+        _ => IdleAction::None,
+    }
+}
+
 /// Save a recorded session to disk as a JSON file.
 pub fn save_session(
-    session: &crate::models::session::RecordedSession,
+    session: &RecordedSession,
     project_root: &Path,
     _project_id: &str,
 ) -> anyhow::Result<PathBuf> {
     let session_dir = project_root.join(".sessions");
     std::fs::create_dir_all(&session_dir)?;
 
-    let path = session_dir.join(format!("{}.session.json", session.id));
+    let path = session_file_path(project_root, &session.id);
     let json = serde_json::to_string_pretty(session)?;
     std::fs::write(&path, json)?;
 
     Ok(path)
 }
 
+/// Pause inserted between the two takes joined by [`merge_sessions`].
+pub const MERGE_SESSION_GAP_MS: u64 = 1_000;
+
+/// Stitch two takes into one session.
+///
+/// Keeps `a`'s id, mode, and sketch link. `b`'s actions are appended with
+/// their timestamps shifted to start [`MERGE_SESSION_GAP_MS`] after `a`'s
+/// last action, so the merged timeline strictly increases across the seam.
+/// `ended_at` is the later of the two.
+pub fn merge_sessions(a: &RecordedSession, b: &RecordedSession) -> RecordedSession {
+    let mut merged = a.clone();
+    let offset = a.actions.last().map_or(0, |captured| {
+        captured.metadata.timestamp_ms + MERGE_SESSION_GAP_MS
+    });
+    let b_start = b
+        .actions
+        .first()
+        .map(|captured| captured.metadata.timestamp_ms)
+        .unwrap_or(0);
+
+    merged.actions.extend(b.actions.iter().map(|captured| {
+        let mut captured = captured.clone();
+        captured.metadata.timestamp_ms =
+            offset + captured.metadata.timestamp_ms.saturating_sub(b_start);
+        captured
+    }));
+    merged.sketch_path = a.sketch_path.clone().or_else(|| b.sketch_path.clone());
+    merged.ended_at = a.ended_at.max(b.ended_at);
+    merged
+}
+
+/// Keep only the actions in `[start_index, end_index)`.
+///
+/// Timestamps are rebased so the first kept action starts at 0. Errors when
+/// the range is inverted or extends past the end of the session.
+pub fn trim_session(
+    session: &RecordedSession,
+    start_index: usize,
+    end_index: usize,
+) -> anyhow::Result<RecordedSession> {
+    if start_index > end_index {
+        anyhow::bail!("Invalid trim range: start {start_index} is after end {end_index}");
+    }
+    if end_index > session.actions.len() {
+        anyhow::bail!(
+            "Invalid trim range: end {end_index} exceeds {} actions",
+            session.actions.len()
+        );
+    }
+
+    let mut trimmed = session.clone();
+    trimmed.actions = session.actions[start_index..end_index].to_vec();
+    if let Some(base) = trimmed
+        .actions
+        .first()
+        .map(|captured| captured.metadata.timestamp_ms)
+    {
+        for captured in &mut trimmed.actions {
+            captured.metadata.timestamp_ms = captured.metadata.timestamp_ms.saturating_sub(base);
+        }
+    }
+    Ok(trimmed)
+}
+
+/// Path of a session's JSON file under `.sessions/`.
+fn session_file_path(project_root: &Path, session_id: &uuid::Uuid) -> PathBuf {
+    project_root
+        .join(".sessions")
+        .join(format!("{session_id}.session.json"))
+}
+
+/// Load a saved session by ID.
+///
+/// The ID must be a UUID, which keeps the lookup inside `.sessions/`.
+/// Selectors are normalized with [`RecordedSession::sanitize`]; actions it
+/// drops are logged and stay dropped if the session is saved again.
+pub fn load_session(project_root: &Path, session_id: &str) -> anyhow::Result<RecordedSession> {
+    let id = uuid::Uuid::parse_str(session_id)
+        .map_err(|_| anyhow::anyhow!("Invalid session id: {session_id}"))?;
+    read_session_file(&session_file_path(project_root, &id))
+}
+
+/// Parse and sanitize one session file.
+fn read_session_file(path: &Path) -> anyhow::Result<RecordedSession> {
+    let data = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read session {}: {e}", path.display()))?;
+    let mut session: RecordedSession = serde_json::from_str(&data)?;
+    let dropped = session.sanitize();
+    if dropped > 0 {
+        log::warn!(
+            "[interaction] session {} has {dropped} action(s) without a usable selector; ignoring them",
+            session.id
+        );
+    }
+    Ok(session)
+}
+
+/// Summarize every saved session under `.sessions/`, newest first.
+///
+/// Sessions are sanitized like [`load_session`], so `action_count` matches what
+/// loading the session returns. Files that fail to parse are skipped with a
+/// warning.
+pub fn list_sessions(project_root: &Path) -> anyhow::Result<Vec<SessionSummary>> {
+    let session_dir = project_root.join(".sessions");
+    let entries = match std::fs::read_dir(&session_dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut summaries = Vec::new();
+    for entry in entries.filter_map(Result::ok) {
+        let path = entry.path();
+        if !path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.ends_with(".session.json"))
+        {
+            continue;
+        }
+        match read_session_file(&path) {
+            Ok(session) => summaries.push(SessionSummary {
+                id: session.id,
+                mode: session.mode,
+                sketch_path: session.sketch_path,
+                started_at: session.started_at,
+                ended_at: session.ended_at,
+                action_count: session.actions.len(),
+            }),
+            Err(e) => log::warn!("[interaction] skipping unreadable session {path:?}: {e}"),
+        }
+    }
+    summaries.sort_by(|a, b| b.started_at.cmp(&a.started_at));
+    Ok(summaries)
+}
+
+/// Point a saved session at a sketch (relative path) and rewrite its file.
+pub fn link_session_to_sketch(
+    project_root: &Path,
+    session_id: &str,
+    sketch_path: &str,
+) -> anyhow::Result<RecordedSession> {
+    let mut session = load_session(project_root, session_id)?;
+    session.sketch_path = Some(sketch_path.to_string());
+    save_session(&session, project_root, "")?;
+    Ok(session)
+}
+
+/// Delete saved sessions beyond the `keep_latest` most recent, along with
+/// their `.sessions/<id>/` screenshot folders.
+///
+/// Sessions linked to a sketch are never deleted. Returns how many were removed.
+pub fn prune_sessions(project_root: &Path, keep_latest: usize) -> anyhow::Result<usize> {
+    let mut removed = 0;
+    for summary in list_sessions(project_root)?.into_iter().skip(keep_latest) {
+        if summary.sketch_path.is_some() {
+            continue;
+        }
+        std::fs::remove_file(session_file_path(project_root, &summary.id))?;
+        let assets = project_root.join(".sessions").join(summary.id.to_string());
+        if assets.is_dir() {
+            std::fs::remove_dir_all(&assets)?;
+        }
+        removed += 1;
+    }
+    Ok(removed)
+}
+
+/// Outcome of [`stabilize_session`].
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct SessionStabilization {
+    /// Actions whose selectors were reordered most-stable first.
+    pub reordered: usize,
+    /// Actions dropped for falling below the confidence threshold.
+    pub dropped: usize,
+}
+
+/// Reorder selectors and drop low-confidence actions in a saved session,
+/// rewriting its file when anything changed.
+pub fn stabilize_session(
+    project_root: &Path,
+    session_id: &str,
+    threshold: f32,
+) -> anyhow::Result<SessionStabilization> {
+    let session = load_session(project_root, session_id)?;
+    let (stabilized, reordered) = cleanup::stabilize_selectors(&session);
+    let (filtered, dropped) = cleanup::filter_low_confidence(&stabilized, threshold);
+    if reordered > 0 || dropped > 0 {
+        save_session(&filtered, project_root, "")?;
+    }
+    Ok(SessionStabilization { reordered, dropped })
+}
+
+/// Saved sessions linked to `sketch_path`, newest first.
+pub fn sessions_for_sketch(
+    project_root: &Path,
+    sketch_path: &str,
+) -> anyhow::Result<Vec<SessionSummary>> {
+    Ok(list_sessions(project_root)?
+        .into_iter()
+        .filter(|summary| summary.sketch_path.as_deref() == Some(sketch_path))
+        .collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::action::{Action, ActionMetadata, SelectorStrategy};
-    use crate::models::session::{CapturedAction, RecordedSession, RecordingMode};
+    use crate::models::action::{Action, ActionMetadata};
+    use crate::models::session::RecordingMode;
     use tempfile::TempDir;
 
+    /// Sidecar stub that answers element screenshots and records the params.
+    #[derive(Default)]
+    struct ScreenshotSidecar {
+        requests: std::sync::Mutex<Vec<(String, serde_json::Value)>>,
+    }
+
+    impl SidecarClient for ScreenshotSidecar {
+        async fn request(
+            &self,
+            method: &str,
+            params: serde_json::Value,
+        ) -> Result<serde_json::Value, String> {
+            self.requests
+                .lock()
+                .unwrap()
+                .push((method.to_string(), params.clone()));
+            Ok(serde_json::json!({
+                "status": "ok",
+                "path": params["output_path"],
+                "element": true,
+            }))
+        }
+    }
+
+    fn profile(browser: &str) -> BrowserProfile {
+        BrowserProfile {
+            browser: browser.into(),
+            browser_name: browser.into(),
+            profile_directory: "Default".into(),
+            display_name: "Person 1".into(),
+            user_data_dir: format!("/profiles/{browser}"),
+        }
+    }
+
+    #[test]
+    fn readiness_skips_open_browsers() {
+        let both = || vec![profile("msedge"), profile("chrome")];
+
+        let idle = readiness_from(
+            both(),
+            BrowserRunningStatus {
+                msedge: false,
+                chrome: false,
+            },
+        );
+        assert_eq!(idle.recommended_channel.as_deref(), Some("msedge"));
+        assert_eq!(idle.reason, "Using Edge");
+
+        let edge_open = readiness_from(
+            both(),
+            BrowserRunningStatus {
+                msedge: true,
+                chrome: false,
+            },
+        );
+        assert_eq!(edge_open.recommended_channel.as_deref(), Some("chrome"));
+        assert_eq!(edge_open.reason, "Edge is open, using Chrome");
+
+        let all_open = readiness_from(
+            both(),
+            BrowserRunningStatus {
+                msedge: true,
+                chrome: true,
+            },
+        );
+        assert_eq!(all_open.recommended_channel, None);
+        assert!(all_open.reason.starts_with("Edge and Chrome are open"));
+
+        let none = readiness_from(
+            Vec::new(),
+            BrowserRunningStatus {
+                msedge: true,
+                chrome: false,
+            },
+        );
+        assert_eq!(none.recommended_channel, None);
+        assert!(none.reason.contains("fresh browser"));
+    }
+
     #[test]
     fn resolve_sidecar_dir_exists() {
         let dir = resolve_sidecar_dir();
@@ -350,6 +913,110 @@ mod tests {
         assert!(dir.to_string_lossy().contains("screenshots"));
     }
 
+    #[test]
+    fn chromium_profile_params_pass_through() {
+        let options = PrepareBrowserOptions {
+            user_data_dir: Some("/data".into()),
+            profile_directory: Some("Default".into()),
+            browser_channel: Some("msedge".into()),
+        };
+        let (params, fallback) = options.sidecar_params();
+        assert_eq!(params["profile_directory"], "Default");
+        assert_eq!(params["browser_channel"], "msedge");
+        assert!(!fallback);
+    }
+
+    #[test]
+    fn firefox_profile_falls_back_to_fresh_context() {
+        let options = PrepareBrowserOptions {
+            user_data_dir: Some("/data".into()),
+            profile_directory: Some("default-release".into()),
+            browser_channel: Some("firefox".into()),
+        };
+        let (params, fallback) = options.sidecar_params();
+        assert_eq!(params, serde_json::json!({ "browser_channel": "firefox" }));
+        assert!(fallback);
+
+        let fresh = PrepareBrowserOptions {
+            browser_channel: Some("firefox".into()),
+            ..Default::default()
+        };
+        assert!(!fresh.sidecar_params().1);
+    }
+
+    #[test]
+    fn selector_validation_is_unique_only_for_single_match() {
+        assert!(!SelectorValidation::from_match_count(0).unique);
+        assert!(SelectorValidation::from_match_count(1).unique);
+        assert!(!SelectorValidation::from_match_count(3).unique);
+    }
+
+    #[test]
+    fn selector_strategy_serializes_as_sidecar_params() {
+        let params = serde_json::to_value(SelectorStrategy::DataTestId("submit".into())).unwrap();
+        assert_eq!(
+            params,
+            serde_json::json!({ "strategy": "DataTestId", "value": "submit" })
+        );
+    }
+
+    fn click_at(selector: &str, timestamp_ms: u64) -> CapturedAction {
+        CapturedAction {
+            action: Action::BrowserClick {
+                selectors: vec![SelectorStrategy::CssSelector(selector.into())],
+            },
+            metadata: ActionMetadata {
+                captured_screenshot: None,
+                selector_strategies: vec![],
+                timestamp_ms,
+                confidence: 0.85,
+                context_snapshot: None,
+            },
+            raw_event: None,
+        }
+    }
+
+    #[test]
+    fn idle_watchdog_warns_once_then_stops_after_grace() {
+        use std::time::Duration;
+
+        let just_under = RECORDING_IDLE_TIMEOUT - Duration::from_secs(1);
+        assert_eq!(idle_action(just_under, false), IdleAction::None);
+        assert_eq!(idle_action(RECORDING_IDLE_TIMEOUT, false), IdleAction::Warn);
+        assert_eq!(idle_action(RECORDING_IDLE_TIMEOUT, true), IdleAction::None);
+
+        let grace = RECORDING_IDLE_AUTO_STOP_GRACE.unwrap();
+        assert_eq!(
+            idle_action(RECORDING_IDLE_TIMEOUT + grace, true),
+            IdleAction::Stop
+        );
+    }
+
+    #[test]
+    fn should_dedup_identical_actions_within_window() {
+        let prev = click_at("#btn", 1_000);
+        assert!(should_dedup(&prev, &click_at("#btn", 1_000)));
+        assert!(should_dedup(
+            &prev,
+            &click_at("#btn", 1_000 + DEDUP_WINDOW_MS)
+        ));
+    }
+
+    #[test]
+    fn should_dedup_keeps_repeats_outside_window() {
+        let prev = click_at("#btn", 1_000);
+        assert!(!should_dedup(
+            &prev,
+            &click_at("#btn", 1_001 + DEDUP_WINDOW_MS)
+        ));
+    }
+
+    #[test]
+    fn should_dedup_keeps_different_actions() {
+        let prev = click_at("#btn", 1_000);
+        assert!(!should_dedup(&prev, &click_at("#other", 1_000)));
+    }
+
     #[test]
     fn save_session_creates_file() {
         let tmp = TempDir::new().unwrap();
@@ -379,4 +1046,258 @@ mod tests {
         assert_eq!(loaded.id, session.id);
         assert_eq!(loaded.actions.len(), 1);
     }
+
+    #[test]
+    fn merge_sessions_keeps_timestamps_monotonic() {
+        let mut a = RecordedSession::new(RecordingMode::StepByStep);
+        a.actions.push(click_at("#one", 100));
+        a.actions.push(click_at("#two", 400));
+        a.ended_at = Some(chrono::Utc::now());
+
+        let mut b = RecordedSession::new(RecordingMode::FreeForm);
+        b.actions.push(click_at("#three", 50));
+        b.actions.push(click_at("#four", 250));
+        b.ended_at = a.ended_at.map(|t| t + chrono::Duration::seconds(30));
+
+        let merged = merge_sessions(&a, &b);
+
+        assert_eq!(merged.id, a.id);
+        assert_eq!(merged.mode, RecordingMode::StepByStep);
+        assert_eq!(merged.ended_at, b.ended_at);
+        let timestamps: Vec<u64> = merged
+            .actions
+            .iter()
+            .map(|captured| captured.metadata.timestamp_ms)
+            .collect();
+        assert_eq!(
+            timestamps,
+            vec![
+                100,
+                400,
+                400 + MERGE_SESSION_GAP_MS,
+                600 + MERGE_SESSION_GAP_MS
+            ]
+        );
+        assert!(timestamps.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn trim_session_keeps_range_and_rebases_timestamps() {
+        let mut session = RecordedSession::new(RecordingMode::FreeForm);
+        for (i, ts) in [1_000, 1_500, 2_200, 3_000].into_iter().enumerate() {
+            session.actions.push(click_at(&format!("#step-{i}"), ts));
+        }
+
+        let trimmed = trim_session(&session, 1, 3).unwrap();
+
+        assert_eq!(trimmed.id, session.id);
+        let timestamps: Vec<u64> = trimmed
+            .actions
+            .iter()
+            .map(|captured| captured.metadata.timestamp_ms)
+            .collect();
+        assert_eq!(timestamps, vec![0, 700]);
+    }
+
+    #[test]
+    fn trim_session_rejects_bad_ranges() {
+        let mut session = RecordedSession::new(RecordingMode::FreeForm);
+        session.actions.push(click_at("#btn", 0));
+        session.actions.push(click_at("#btn", 500));
+
+        assert!(trim_session(&session, 2, 1).is_err());
+        assert!(trim_session(&session, 0, 3).is_err());
+        assert!(trim_session(&session, 2, 2).unwrap().actions.is_empty());
+    }
+
+    #[test]
+    fn load_session_roundtrips_and_rejects_non_uuid_ids() {
+        let tmp = TempDir::new().unwrap();
+        let mut session = RecordedSession::new(RecordingMode::FreeForm);
+        session.actions.push(click_at("#btn", 10));
+        save_session(&session, tmp.path(), "").unwrap();
+
+        let loaded = load_session(tmp.path(), &session.id.to_string()).unwrap();
+        assert_eq!(loaded.id, session.id);
+        assert_eq!(loaded.actions.len(), 1);
+
+        assert!(load_session(tmp.path(), "../project").is_err());
+    }
+
+    #[test]
+    fn list_sessions_counts_actions_the_way_load_session_returns_them() {
+        let tmp = TempDir::new().unwrap();
+        let mut session = RecordedSession::new(RecordingMode::FreeForm);
+        session.actions.push(click_at("#btn", 10));
+        session.actions.push(click_at("   ", 20));
+        save_session(&session, tmp.path(), "").unwrap();
+
+        let loaded = load_session(tmp.path(), &session.id.to_string()).unwrap();
+        let summaries = list_sessions(tmp.path()).unwrap();
+
+        assert_eq!(loaded.actions.len(), 1);
+        assert_eq!(summaries[0].action_count, loaded.actions.len());
+    }
+
+    #[test]
+    fn list_sessions_summarizes_newest_first() {
+        let tmp = TempDir::new().unwrap();
+        assert!(list_sessions(tmp.path()).unwrap().is_empty());
+
+        let mut older = RecordedSession::new(RecordingMode::FreeForm);
+        older.started_at -= chrono::Duration::minutes(5);
+        older.actions.push(click_at("#a", 10));
+        save_session(&older, tmp.path(), "").unwrap();
+
+        let mut newer = RecordedSession::new(RecordingMode::StepByStep);
+        newer.actions.push(click_at("#a", 10));
+        newer.actions.push(click_at("#b", 20));
+        newer.actions.push(click_at("#c", 30));
+        save_session(&newer, tmp.path(), "").unwrap();
+        std::fs::write(tmp.path().join(".sessions/notes.txt"), "ignore me").unwrap();
+
+        let summaries = list_sessions(tmp.path()).unwrap();
+        assert_eq!(summaries.len(), 2);
+        assert_eq!(summaries[0].id, newer.id);
+        assert_eq!(summaries[0].mode, RecordingMode::StepByStep);
+        assert_eq!(summaries[0].action_count, 3);
+        assert_eq!(summaries[1].id, older.id);
+        assert_eq!(summaries[1].action_count, 1);
+    }
+
+    #[test]
+    fn linked_sessions_filter_by_sketch_path() {
+        let tmp = TempDir::new().unwrap();
+        let linked = RecordedSession::new(RecordingMode::FreeForm);
+        let other = RecordedSession::new(RecordingMode::FreeForm);
+        save_session(&linked, tmp.path(), "").unwrap();
+        save_session(&other, tmp.path(), "").unwrap();
+
+        let updated =
+            link_session_to_sketch(tmp.path(), &linked.id.to_string(), "intro.sk").unwrap();
+        assert_eq!(updated.sketch_path.as_deref(), Some("intro.sk"));
+        let reloaded = load_session(tmp.path(), &linked.id.to_string()).unwrap();
+        assert_eq!(reloaded.sketch_path.as_deref(), Some("intro.sk"));
+
+        let matches = sessions_for_sketch(tmp.path(), "intro.sk").unwrap();
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].id, linked.id);
+        assert!(sessions_for_sketch(tmp.path(), "outro.sk")
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn prune_sessions_keeps_latest_and_linked_sessions() {
+        let tmp = TempDir::new().unwrap();
+        let mut sessions = Vec::new();
+        for minutes_ago in [0, 10, 20, 30] {
+            let mut session = RecordedSession::new(RecordingMode::FreeForm);
+            session.started_at -= chrono::Duration::minutes(minutes_ago);
+            save_session(&session, tmp.path(), "").unwrap();
+            sessions.push(session);
+        }
+        let (newest, linked, old_a, old_b) =
+            (&sessions[0], &sessions[1], &sessions[2], &sessions[3]);
+        link_session_to_sketch(tmp.path(), &linked.id.to_string(), "intro.sk").unwrap();
+        let shots = resolve_screenshots_dir(tmp.path(), "", &old_a.id.to_string());
+        std::fs::create_dir_all(&shots).unwrap();
+        std::fs::write(shots.join("step_1.png"), b"png").unwrap();
+
+        assert_eq!(prune_sessions(tmp.path(), 1).unwrap(), 2);
+
+        let remaining: Vec<_> = list_sessions(tmp.path())
+            .unwrap()
+            .into_iter()
+            .map(|summary| summary.id)
+            .collect();
+        assert_eq!(remaining, vec![newest.id, linked.id]);
+        assert!(!tmp
+            .path()
+            .join(".sessions")
+            .join(old_a.id.to_string())
+            .exists());
+        assert!(load_session(tmp.path(), &old_b.id.to_string()).is_err());
+    }
+
+    #[test]
+    fn stabilize_session_rewrites_brittle_first_selectors() {
+        let tmp = TempDir::new().unwrap();
+        let mut session = RecordedSession::new(RecordingMode::FreeForm);
+        let mut click = click_at("div > button", 10);
+        click.action = Action::BrowserClick {
+            selectors: vec![
+                SelectorStrategy::CssSelector("div > button".into()),
+                SelectorStrategy::DataTestId("save".into()),
+            ],
+        };
+        session.actions.push(click);
+        let mut guess = click_at("#maybe", 20);
+        guess.metadata.confidence = 0.1;
+        session.actions.push(guess);
+        save_session(&session, tmp.path(), "").unwrap();
+
+        let outcome = stabilize_session(tmp.path(), &session.id.to_string(), 0.3).unwrap();
+
+        assert_eq!(
+            outcome,
+            SessionStabilization {
+                reordered: 1,
+                dropped: 1
+            }
+        );
+        let saved = load_session(tmp.path(), &session.id.to_string()).unwrap();
+        assert_eq!(saved.actions.len(), 1);
+        let Action::BrowserClick { selectors } = &saved.actions[0].action else {
+            panic!("expected a click");
+        };
+        assert_eq!(selectors[0], SelectorStrategy::DataTestId("save".into()));
+    }
+
+    #[test]
+    fn legacy_sketch_id_field_still_links_session() {
+        let tmp = TempDir::new().unwrap();
+        let session = RecordedSession::new(RecordingMode::FreeForm);
+        let mut json = serde_json::to_value(&session).unwrap();
+        let obj = json.as_object_mut().unwrap();
+        obj.remove("sketch_path");
+        obj.insert("sketch_id".into(), serde_json::json!("legacy.sk"));
+        std::fs::create_dir_all(tmp.path().join(".sessions")).unwrap();
+        std::fs::write(
+            session_file_path(tmp.path(), &session.id),
+            serde_json::to_string(&json).unwrap(),
+        )
+        .unwrap();
+
+        let loaded = load_session(tmp.path(), &session.id.to_string()).unwrap();
+        assert_eq!(loaded.sketch_path.as_deref(), Some("legacy.sk"));
+        assert_eq!(
+            sessions_for_sketch(tmp.path(), "legacy.sk").unwrap().len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn capture_element_returns_project_relative_path() {
+        let sidecar = ScreenshotSidecar::default();
+        let root = Path::new("/projects/demo");
+        let shots = resolve_screenshots_dir(root, "", "session-1");
+        let selector = SelectorStrategy::DataTestId("save".into());
+
+        let path = capture_element(&sidecar, &selector, root, &shots)
+            .await
+            .unwrap();
+
+        assert!(path.starts_with(".sessions/session-1/screenshots"));
+        assert_eq!(path.extension().unwrap(), "png");
+        let requests = sidecar.requests.lock().unwrap();
+        let (method, params) = &requests[0];
+        assert_eq!(method, "browser.screenshotElement");
+        assert_eq!(params["strategy"], "DataTestId");
+        assert_eq!(params["value"], "save");
+        assert_eq!(
+            Path::new(params["output_path"].as_str().unwrap()),
+            root.join(&path)
+        );
+    }
 }
@@ -15,9 +15,16 @@
 use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
 
+use draftline::tauri_contract as contract;
+
+use crate::engine::agent::llm::LlmConfig;
 use crate::engine::draftline_adapter::CutReadyDraftlineAdapter;
-use crate::models::script::{ProjectEntry, ProjectManifest, ProjectView, RepoView};
-use crate::models::sketch::{NoteSummary, Sketch, SketchSummary, Storyboard, StoryboardSummary};
+use crate::models::script::{
+    ProjectEntry, ProjectManifest, ProjectView, RepoView, MANIFEST_SCHEMA_VERSION,
+};
+use crate::models::sketch::{
+    NoteSummary, PlanningRow, Sketch, SketchSummary, Storyboard, StoryboardItem, StoryboardSummary,
+};
 
 const LOCKS_PATH: &str = ".cutready/locks.json";
 
@@ -50,7 +57,16 @@ pub struct SidebarOrder {
 /// Resolve a user-provided relative path against a project root,
 /// ensuring the result stays within the project directory.
 ///
-/// Rejects absolute paths, `..` traversal, and any result that escapes root.
+/// Contract:
+/// - absolute paths, drive prefixes, and any `..` component are rejected
+///   before touching the filesystem;
+/// - when the target (or its parent) exists, both it and the root are
+///   canonicalized and the result must stay under the canonical root, which
+///   rejects symlinks that point outside the project;
+/// - the returned path is `root.join(relative_path)`, not the canonical form.
+///
+/// Every command that accepts a project-relative path from the frontend or from
+/// project content must go through this before reading or writing.
 pub fn safe_resolve(root: &Path, relative_path: &str) -> Result<PathBuf, ProjectError> {
     let rel = Path::new(relative_path);
 
@@ -96,6 +112,37 @@ pub fn safe_resolve(root: &Path, relative_path: &str) -> Result<PathBuf, Project
     Ok(resolved)
 }
 
+// ── Atomic writes ──────────────────────────────────────────────────
+
+/// Write a file so readers never observe a partial write.
+///
+/// Content goes to a hidden temp file next to `path`, which is then renamed
+/// over the target (atomic on the same filesystem).
+pub fn atomic_write(path: &Path, contents: impl AsRef<[u8]>) -> Result<(), ProjectError> {
+    atomic_write_with(path, |temp| std::fs::write(temp, contents.as_ref()))
+}
+
+fn atomic_write_with(
+    path: &Path,
+    write: impl FnOnce(&Path) -> std::io::Result<()>,
+) -> Result<(), ProjectError> {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy())
+        .unwrap_or_else(|| "file".into());
+    let temp = path.with_file_name(format!(
+        ".{file_name}.{}.tmp",
+        uuid::Uuid::new_v4().simple()
+    ));
+
+    let result = write(&temp).and_then(|()| std::fs::rename(&temp, path));
+    if let Err(e) = result {
+        let _ = std::fs::remove_file(&temp);
+        return Err(ProjectError::Io(e.to_string()));
+    }
+    Ok(())
+}
+
 // ── Project folder operations ──────────────────────────────────────
 
 /// Initialize a new project in the given folder.
@@ -110,6 +157,163 @@ pub fn init_project_folder(root: &Path) -> Result<ProjectView, ProjectError> {
     Ok(ProjectView::new(root.to_path_buf()))
 }
 
+/// Adopt an existing git repository as a Draftline workspace, keeping its history.
+///
+/// Unlike [`init_project_folder`], the folder must already contain `.git`.
+/// Draftline adopts the repository in place, so existing commits are never
+/// rewritten and its branches surface as timelines. Picking the project to
+/// activate is left to opening the folder afterwards.
+pub fn adopt_repo(
+    repo_dir: &Path,
+    context: &mut contract::DraftlineCommandContext<'_>,
+) -> Result<(), ProjectError> {
+    if !repo_dir.join(".git").exists() {
+        return Err(ProjectError::NotFound(format!(
+            "{} is not a git repository",
+            repo_dir.display()
+        )));
+    }
+
+    let request = contract::WorkspaceRequest {
+        workspace_path: repo_dir.to_path_buf(),
+    };
+    contract::adopt_workspace_with_context(context, request)
+        .map_err(|e| ProjectError::Io(e.to_string()))?;
+    Ok(())
+}
+
+/// Starter content for a new project.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjectTemplate {
+    /// An empty project, same as [`init_project_folder`].
+    Blank,
+    ProductDemo,
+    Tutorial,
+}
+
+/// A placeholder sketch seeded by a template: file path, title, and the
+/// narrative/demo-action pairs of its rows.
+type TemplateSketch = (
+    &'static str,
+    &'static str,
+    &'static [(&'static str, &'static str)],
+);
+
+const PRODUCT_DEMO_SKETCHES: &[TemplateSketch] = &[
+    (
+        "sketches/intro.sk",
+        "Introduction",
+        &[(
+            "Introduce the problem your product solves.",
+            "Show the landing page.",
+        )],
+    ),
+    (
+        "sketches/walkthrough.sk",
+        "Feature walkthrough",
+        &[
+            (
+                "Walk through the headline feature.",
+                "Open the main screen.",
+            ),
+            (
+                "Show the result the viewer cares about.",
+                "Finish the task.",
+            ),
+        ],
+    ),
+];
+
+const TUTORIAL_SKETCHES: &[TemplateSketch] = &[
+    (
+        "sketches/setup.sk",
+        "Setup",
+        &[(
+            "Explain what the viewer needs before starting.",
+            "Show the prerequisites.",
+        )],
+    ),
+    (
+        "sketches/first-steps.sk",
+        "First steps",
+        &[
+            (
+                "Do the first task step by step.",
+                "Perform each step slowly.",
+            ),
+            ("Point out what changed.", "Highlight the result."),
+        ],
+    ),
+    (
+        "sketches/next-steps.sk",
+        "Next steps",
+        &[(
+            "Recap and suggest where to go next.",
+            "Show the docs or next tutorial.",
+        )],
+    ),
+];
+
+/// Initialize a new project seeded with a template's starter storyboard,
+/// placeholder sketches, and README note.
+///
+/// The content is written as regular project files, so the first saved
+/// version captures it. Existing files are never overwritten.
+pub fn create_from_template(
+    dest_dir: &Path,
+    template: ProjectTemplate,
+) -> Result<ProjectView, ProjectError> {
+    let view = init_project_folder(dest_dir)?;
+    let (title, storyboard_path, sketches) = match template {
+        ProjectTemplate::Blank => return Ok(view),
+        ProjectTemplate::ProductDemo => (
+            "Product demo",
+            "storyboards/product-demo.sb",
+            PRODUCT_DEMO_SKETCHES,
+        ),
+        ProjectTemplate::Tutorial => ("Tutorial", "storyboards/tutorial.sb", TUTORIAL_SKETCHES),
+    };
+
+    let mut storyboard = Storyboard::new(title);
+    for (path, sketch_title, rows) in sketches {
+        let mut sketch = Sketch::new(*sketch_title);
+        sketch.rows = rows
+            .iter()
+            .map(|(narrative, demo_actions)| PlanningRow {
+                narrative: narrative.to_string(),
+                demo_actions: demo_actions.to_string(),
+                ..PlanningRow::new()
+            })
+            .collect();
+        let abs_path = dest_dir.join(path);
+        if !abs_path.exists() {
+            write_sketch(&sketch, &abs_path, dest_dir)?;
+        }
+        storyboard.items.push(StoryboardItem::SketchRef {
+            path: path.to_string(),
+        });
+    }
+
+    let storyboard_abs = dest_dir.join(storyboard_path);
+    if !storyboard_abs.exists() {
+        write_storyboard(&storyboard, &storyboard_abs, dest_dir)?;
+    }
+    let readme = dest_dir.join("README.md");
+    if !readme.exists() {
+        write_note(
+            &readme,
+            &format!(
+                "# {}\n\nStart with the `{title}` storyboard. Replace the placeholder rows in each sketch with your own narration and demo steps.\n",
+                view.name
+            ),
+            false,
+        )?;
+    }
+
+    Ok(view)
+}
+
 // ── Multi-project manifest ────────────────────────────────────────
 
 const MANIFEST_PATH: &str = ".cutready/projects.json";
@@ -181,7 +385,74 @@ pub fn write_manifest(repo_root: &Path, manifest: &ProjectManifest) -> Result<()
     }
     let json =
         serde_json::to_string_pretty(manifest).map_err(|e| ProjectError::Io(e.to_string()))?;
-    std::fs::write(&path, json).map_err(|e| ProjectError::Io(e.to_string()))
+    atomic_write(&path, json)
+}
+
+/// Manifest migrations in order; entry `n` upgrades schema version `n` to `n + 1`.
+const MANIFEST_MIGRATIONS: &[fn(&Path, &mut ProjectManifest)] = &[externalize_inline_visuals];
+
+/// Apply pending migrations and bump `schema_version`. Returns whether any ran.
+pub fn migrate_manifest(repo_root: &Path, manifest: &mut ProjectManifest) -> bool {
+    let mut migrated = false;
+    while let Some(migration) = MANIFEST_MIGRATIONS.get(manifest.schema_version as usize) {
+        migration(repo_root, manifest);
+        manifest.schema_version += 1;
+        migrated = true;
+    }
+    migrated
+}
+
+/// Refuse a manifest written by a newer CutReady. Its layout may have moved
+/// on, and migrating or re-saving it here could lose data.
+fn ensure_manifest_supported(repo_root: &Path) -> Result<(), ProjectError> {
+    let path = repo_root.join(MANIFEST_PATH);
+    let Ok(data) = std::fs::read_to_string(&path) else {
+        return Ok(());
+    };
+    let found = serde_json::from_str::<serde_json::Value>(&data)
+        .ok()
+        .and_then(|v| v.get("schema_version")?.as_u64())
+        .unwrap_or(0);
+    if found > u64::from(MANIFEST_SCHEMA_VERSION) {
+        return Err(ProjectError::UnsupportedManifest {
+            found,
+            supported: MANIFEST_SCHEMA_VERSION,
+        });
+    }
+    Ok(())
+}
+
+/// Migrate the repo's manifest and re-save it when anything changed.
+/// Returns whether a migration occurred.
+pub fn migrate_manifest_file(repo_root: &Path) -> Result<bool, ProjectError> {
+    let Some(mut manifest) = read_manifest_result(repo_root)? else {
+        return Ok(false);
+    };
+    if !migrate_manifest(repo_root, &mut manifest) {
+        return Ok(false);
+    }
+    write_manifest(repo_root, &manifest)?;
+    Ok(true)
+}
+
+/// v0 → v1: move legacy inline visuals in every listed project's sketches
+/// out to `.cutready/visuals/`.
+fn externalize_inline_visuals(repo_root: &Path, manifest: &mut ProjectManifest) {
+    for entry in &manifest.projects {
+        let Ok(project_root) = safe_resolve(repo_root, &entry.path) else {
+            continue;
+        };
+        let Ok(sketches) = scan_sketches(&project_root) else {
+            continue;
+        };
+        for summary in sketches {
+            if let Err(e) =
+                read_sketch_with_migration(&project_root.join(&summary.path), &project_root)
+            {
+                log::warn!("[project] failed to migrate sketch {}: {e}", summary.path);
+            }
+        }
+    }
 }
 
 /// Inspect workspace manifest health without mutating the workspace.
@@ -324,7 +595,10 @@ fn repaired_manifest(
         }
     }
 
-    ProjectManifest { projects }
+    ProjectManifest {
+        schema_version: MANIFEST_SCHEMA_VERSION,
+        projects,
+    }
 }
 
 fn stale_manifest_entries(
@@ -511,6 +785,7 @@ pub fn open_repo(root: &Path) -> Result<(RepoView, Vec<ProjectEntry>), ProjectEr
     if !root.exists() || !root.is_dir() {
         return Err(ProjectError::NotFound(root.to_string_lossy().into_owned()));
     }
+    ensure_manifest_supported(root)?;
 
     // Draftline owns workspace initialization and existing-workspace detection.
     CutReadyDraftlineAdapter::open_project(root).map_err(|e| ProjectError::Io(e.to_string()))?;
@@ -519,6 +794,10 @@ pub fn open_repo(root: &Path) -> Result<(RepoView, Vec<ProjectEntry>), ProjectEr
     // This fixes workspaces migrated before the asset-move fix.
     repair_orphaned_assets(root);
 
+    if let Err(e) = migrate_manifest_file(root) {
+        log::warn!("[project] manifest migration failed: {e}");
+    }
+
     let repo = RepoView::new(root.to_path_buf());
     let projects = list_projects(root);
     Ok((repo, projects))
@@ -627,6 +906,7 @@ pub fn create_project_in_repo(
     }
 
     let mut manifest = read_manifest_result(repo_root)?.unwrap_or(ProjectManifest {
+        schema_version: MANIFEST_SCHEMA_VERSION,
         projects: Vec::new(),
     });
 
@@ -710,6 +990,7 @@ pub fn migrate_to_multi_project(
 
     // Create manifest with the single migrated project
     let manifest = ProjectManifest {
+        schema_version: MANIFEST_SCHEMA_VERSION,
         projects: vec![entry.clone()],
     };
     write_manifest(repo_root, &manifest)?;
@@ -777,7 +1058,7 @@ pub fn write_sketch(
 
     let json =
         serde_json::to_string_pretty(sketch).map_err(|e| ProjectError::Serialize(e.to_string()))?;
-    std::fs::write(path, json).map_err(|e| ProjectError::Io(e.to_string()))?;
+    atomic_write(path, json)?;
 
     Ok(())
 }
@@ -943,6 +1224,138 @@ fn read_sketch_inner(path: &Path, project_root: Option<&Path>) -> Result<Sketch,
     Ok(sketch)
 }
 
+/// Copy a sketch from one project into another, bringing its screenshots along.
+///
+/// Referenced screenshots are copied into the destination's
+/// `.cutready/screenshots/` and rows are rewritten to point at the copies. A
+/// screenshot that is missing on disk clears that row's reference. Returns the
+/// sketch's path in the destination, which matches `sketch_path`.
+pub fn copy_sketch_between(
+    src_dir: &Path,
+    dst_dir: &Path,
+    sketch_path: &str,
+) -> Result<String, ProjectError> {
+    let source_abs = safe_resolve(src_dir, sketch_path)?;
+    let target_abs = safe_resolve(dst_dir, sketch_path)?;
+    if target_abs.exists() {
+        return Err(ProjectError::Io(format!(
+            "{sketch_path} already exists in the destination project"
+        )));
+    }
+    let mut sketch = read_sketch_with_migration(&source_abs, src_dir)?;
+    for (index, row) in sketch.rows.iter_mut().enumerate() {
+        let Some(screenshot) = row.screenshot.take() else {
+            continue;
+        };
+        row.screenshot = copy_screenshot_between(src_dir, dst_dir, &screenshot)?;
+        if row.screenshot.is_none() {
+            log::warn!(
+                "[copy_sketch] row {} screenshot {screenshot} is missing; cleared the reference",
+                index + 1
+            );
+        }
+    }
+    write_sketch(&sketch, &target_abs, dst_dir)?;
+    Ok(sketch_path.to_string())
+}
+
+/// Copy one screenshot into `dst_dir/.cutready/screenshots/`, returning its new
+/// relative path, or `None` when the source file is missing. An identical file
+/// already at the destination is reused; a different one gets a numbered name.
+fn copy_screenshot_between(
+    src_dir: &Path,
+    dst_dir: &Path,
+    relative_path: &str,
+) -> Result<Option<String>, ProjectError> {
+    let Ok(source) = safe_resolve(src_dir, relative_path) else {
+        return Ok(None);
+    };
+    if !source.is_file() {
+        return Ok(None);
+    }
+    let bytes = std::fs::read(&source).map_err(|e| ProjectError::Io(e.to_string()))?;
+    let stem = source
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("screenshot");
+    let extension = source
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| format!(".{ext}"))
+        .unwrap_or_default();
+
+    let dir = dst_dir.join(".cutready").join("screenshots");
+    std::fs::create_dir_all(&dir).map_err(|e| ProjectError::Io(e.to_string()))?;
+    let mut file_name = format!("{stem}{extension}");
+    let mut suffix = 2;
+    loop {
+        let target = dir.join(&file_name);
+        if !target.exists() {
+            std::fs::write(&target, &bytes).map_err(|e| ProjectError::Io(e.to_string()))?;
+            break;
+        }
+        if std::fs::read(&target).is_ok_and(|existing| existing == bytes) {
+            break;
+        }
+        file_name = format!("{stem}-{suffix}{extension}");
+        suffix += 1;
+    }
+    Ok(Some(format!(".cutready/screenshots/{file_name}")))
+}
+
+/// Result of [`export_sketch_images_zip`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize)]
+pub struct SketchImagesExport {
+    /// Number of images written to the archive.
+    pub written: usize,
+    /// 1-based row numbers with no screenshot, or whose file is missing.
+    pub skipped_rows: Vec<usize>,
+}
+
+/// Write every row screenshot of a sketch into a zip at `out_path`.
+///
+/// Each image is stored as `step_{n}.{ext}`, where `n` is the 1-based row
+/// number, so entries line up with the sketch table.
+pub fn export_sketch_images_zip(
+    project_dir: &Path,
+    sketch_path: &str,
+    out_path: &Path,
+) -> Result<SketchImagesExport, ProjectError> {
+    use std::io::Write;
+    use zip::write::SimpleFileOptions;
+
+    let sketch = read_sketch(&safe_resolve(project_dir, sketch_path)?)?;
+    let file = std::fs::File::create(out_path).map_err(|e| ProjectError::Io(e.to_string()))?;
+    let mut zip = zip::ZipWriter::new(file);
+    let options = SimpleFileOptions::default();
+
+    let mut export = SketchImagesExport::default();
+    for (index, row) in sketch.rows.iter().enumerate() {
+        let row_number = index + 1;
+        let source = row
+            .screenshot
+            .as_deref()
+            .and_then(|shot| safe_resolve(project_dir, shot).ok())
+            .filter(|path| path.is_file());
+        let Some(source) = source else {
+            export.skipped_rows.push(row_number);
+            continue;
+        };
+        let extension = source
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or("png");
+        let bytes = std::fs::read(&source).map_err(|e| ProjectError::Io(e.to_string()))?;
+        zip.start_file(format!("step_{row_number}.{extension}"), options)
+            .map_err(|e| ProjectError::Io(e.to_string()))?;
+        zip.write_all(&bytes)
+            .map_err(|e| ProjectError::Io(e.to_string()))?;
+        export.written += 1;
+    }
+    zip.finish().map_err(|e| ProjectError::Io(e.to_string()))?;
+    Ok(export)
+}
+
 /// Delete an unlocked sketch file and remove storyboard references to it.
 pub fn delete_sketch(path: &Path, project_root: &Path) -> Result<(), ProjectError> {
     if path.exists() {
@@ -1784,7 +2197,7 @@ pub fn write_storyboard(
 
     let json =
         serde_json::to_string_pretty(sb).map_err(|e| ProjectError::Serialize(e.to_string()))?;
-    std::fs::write(path, json).map_err(|e| ProjectError::Io(e.to_string()))?;
+    atomic_write(path, json)?;
 
     Ok(())
 }
@@ -1819,6 +2232,120 @@ pub fn delete_storyboard(path: &Path, _project_root: &Path) -> Result<(), Projec
     Ok(())
 }
 
+/// Return the sketch paths a storyboard references that no longer exist on disk.
+///
+/// Covers both loose `SketchRef` items and `Section.sketches`. Paths that fail
+/// `safe_resolve` are reported as dangling too, since they can never load.
+pub fn validate_storyboard(project_root: &Path, storyboard: &Storyboard) -> Vec<String> {
+    storyboard
+        .sketch_paths()
+        .into_iter()
+        .filter(|path| {
+            safe_resolve(project_root, path)
+                .map(|abs| !abs.is_file())
+                .unwrap_or(true)
+        })
+        .collect()
+}
+
+// ── Markdown export ────────────────────────────────────────────────
+
+/// Render a sketch's description and planning table as Markdown.
+///
+/// Headings are left to the caller so the table can be nested under any level.
+pub fn sketch_to_markdown(sketch: &Sketch) -> String {
+    let mut out = String::new();
+    let description = crate::engine::export::description_text(&sketch.description);
+    if !description.is_empty() {
+        out.push_str(&description);
+        out.push_str("\n\n");
+    }
+    if sketch.rows.is_empty() {
+        out.push_str("_No planning rows._\n");
+        return out;
+    }
+    out.push_str("| Time | Narrative | Demo Actions | Screenshot |\n");
+    out.push_str("| --- | --- | --- | --- |\n");
+    for row in &sketch.rows {
+        out.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            markdown_table_cell(&row.time),
+            markdown_table_cell(&row.narrative),
+            markdown_table_cell(&row.demo_actions),
+            row.screenshot
+                .as_deref()
+                .map(|path| format!("![]({path})"))
+                .unwrap_or_default(),
+        ));
+    }
+    out
+}
+
+/// Render a storyboard as a single Markdown outline.
+///
+/// Sections become H2 headings with their sketches as H3; loose sketch refs are
+/// rendered at H2. Sketches missing on disk render a `(missing: path)` placeholder.
+pub fn storyboard_to_markdown(
+    project_root: &Path,
+    storyboard: &Storyboard,
+) -> Result<String, ProjectError> {
+    let mut out = format!("# {}\n\n", storyboard.title);
+    if !storyboard.description.trim().is_empty() {
+        out.push_str(storyboard.description.trim());
+        out.push_str("\n\n");
+    }
+    for item in &storyboard.items {
+        match item {
+            StoryboardItem::SketchRef { path } => {
+                push_sketch_markdown(&mut out, project_root, path, "##")?;
+            }
+            StoryboardItem::Section {
+                title,
+                description,
+                sketches,
+            } => {
+                out.push_str(&format!("## {title}\n\n"));
+                if !description.trim().is_empty() {
+                    out.push_str(description.trim());
+                    out.push_str("\n\n");
+                }
+                for path in sketches {
+                    push_sketch_markdown(&mut out, project_root, path, "###")?;
+                }
+            }
+        }
+    }
+    Ok(out)
+}
+
+fn push_sketch_markdown(
+    out: &mut String,
+    project_root: &Path,
+    sketch_path: &str,
+    heading: &str,
+) -> Result<(), ProjectError> {
+    let abs_path = match safe_resolve(project_root, sketch_path) {
+        Ok(path) if path.is_file() => path,
+        _ => {
+            out.push_str(&format!("(missing: {sketch_path})\n\n"));
+            return Ok(());
+        }
+    };
+    let sketch = read_sketch(&abs_path)?;
+    out.push_str(&format!("{heading} {}\n\n", sketch.title));
+    out.push_str(&sketch_to_markdown(&sketch));
+    out.push('\n');
+    Ok(())
+}
+
+fn markdown_table_cell(value: &str) -> String {
+    value
+        .trim()
+        .replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace('\n', "<br>")
+}
+
 // ── Folder scanning ────────────────────────────────────────────────
 
 /// Recursively scan a project folder for all `.sk` files.
@@ -1841,6 +2368,27 @@ pub fn scan_sketches(project_root: &Path) -> Result<Vec<SketchSummary>, ProjectE
     Ok(summaries)
 }
 
+/// Scan the `sketches/` folder, including nested folders such as
+/// `sketches/flows/login.sk`. Paths are relative to the project root and
+/// results are ordered by path so folders stay grouped.
+pub fn scan_sketches_tree(project_root: &Path) -> Result<Vec<SketchSummary>, ProjectError> {
+    let mut summaries = Vec::new();
+    scan_files_recursive(
+        &project_root.join("sketches"),
+        project_root,
+        "sk",
+        &mut |rel_path, abs_path| {
+            if let Ok(data) = std::fs::read_to_string(abs_path) {
+                if let Ok(sketch) = serde_json::from_str::<Sketch>(&data) {
+                    summaries.push(SketchSummary::from_sketch(&sketch, rel_path));
+                }
+            }
+        },
+    )?;
+    summaries.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(summaries)
+}
+
 /// Recursively scan a project folder for all `.sb` files.
 /// Returns summaries with relative paths from project root.
 pub fn scan_storyboards(project_root: &Path) -> Result<Vec<StoryboardSummary>, ProjectError> {
@@ -1920,6 +2468,98 @@ pub fn scan_notes(project_root: &Path) -> Result<Vec<NoteSummary>, ProjectError>
     Ok(summaries)
 }
 
+/// Storage breakdown for a project, used to decide whether cleanup is worth it.
+///
+/// Byte counts are apparent file sizes, so on-disk usage can differ with block
+/// rounding or compression. `screenshot_bytes` is exact for the flat
+/// `.cutready/screenshots` folder. `git_bytes` covers the repository's `.git`,
+/// which is shared by every project in a multi-project workspace.
+/// `total_bytes` is everything under the project folder, including `.git` when
+/// the project is the repository root.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize)]
+pub struct ProjectStats {
+    pub sketch_count: usize,
+    pub note_count: usize,
+    pub storyboard_count: usize,
+    pub screenshot_bytes: u64,
+    pub git_bytes: u64,
+    pub total_bytes: u64,
+    pub version_count: usize,
+}
+
+/// Count documents and measure storage for a project.
+pub fn project_stats(repo_root: &Path, project_root: &Path) -> Result<ProjectStats, ProjectError> {
+    let mut stats = ProjectStats::default();
+    for (extension, count) in [
+        ("sk", &mut stats.sketch_count),
+        ("md", &mut stats.note_count),
+        ("sb", &mut stats.storyboard_count),
+    ] {
+        scan_files_recursive(project_root, project_root, extension, &mut |_, _| {
+            *count += 1
+        })?;
+    }
+
+    // Screenshots are written flat, so a single directory listing is enough.
+    let screenshots = project_root.join(".cutready").join("screenshots");
+    if let Ok(entries) = std::fs::read_dir(&screenshots) {
+        stats.screenshot_bytes = entries
+            .filter_map(Result::ok)
+            .filter_map(|entry| entry.metadata().ok())
+            .filter(|meta| meta.is_file())
+            .map(|meta| meta.len())
+            .sum();
+    }
+    let git_dir = repo_root.join(".git");
+    stats.git_bytes = directory_size(&git_dir).unwrap_or_else(|e| {
+        log::warn!("[project] could not measure {}: {e}", git_dir.display());
+        0
+    });
+    // Reuse `git_bytes` rather than walking `.git` again for a root project.
+    stats.total_bytes = directory_size_except(project_root, Some(&git_dir))?;
+    if git_dir.starts_with(project_root) {
+        stats.total_bytes += stats.git_bytes;
+    }
+    stats.version_count = CutReadyDraftlineAdapter::open_project(repo_root)
+        .and_then(|adapter| adapter.versions())
+        .map(|versions| versions.len())
+        .unwrap_or(0);
+    Ok(stats)
+}
+
+/// Total size of the files under `path`, without following symlinks.
+/// A missing directory has size zero. Only an unreadable `path` is an error;
+/// entries below it that can't be read are skipped.
+pub fn directory_size(path: &Path) -> Result<u64, ProjectError> {
+    directory_size_except(path, None)
+}
+
+/// [`directory_size`] that leaves out the `skip` directory wherever it appears.
+fn directory_size_except(path: &Path, skip: Option<&Path>) -> Result<u64, ProjectError> {
+    let mut total = 0;
+    if !path.exists() {
+        return Ok(0);
+    }
+    let entries = std::fs::read_dir(path).map_err(|e| {
+        ProjectError::Io(format!("Could not read directory {}: {e}", path.display()))
+    })?;
+    for entry in entries.filter_map(Result::ok) {
+        let entry_path = entry.path();
+        if skip == Some(entry_path.as_path()) {
+            continue;
+        }
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if metadata.is_dir() {
+            total += directory_size_except(&entry_path, skip).unwrap_or(0);
+        } else {
+            total += metadata.len();
+        }
+    }
+    Ok(total)
+}
+
 /// Entry for the flat file listing returned by `scan_all_files`.
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct FileEntry {
@@ -2008,12 +2648,72 @@ pub fn read_note(path: &Path) -> Result<String, ProjectError> {
     std::fs::read_to_string(path).map_err(|e| ProjectError::Io(e.to_string()))
 }
 
-/// Write a note file (plain text).
-pub fn write_note(path: &Path, content: &str) -> Result<(), ProjectError> {
+/// Write a note file (plain text). With `normalize_eol`, CRLF line endings
+/// are written as LF.
+pub fn write_note(path: &Path, content: &str, normalize_eol: bool) -> Result<(), ProjectError> {
     if let Some(parent) = path.parent() {
         std::fs::create_dir_all(parent).map_err(|e| ProjectError::Io(e.to_string()))?;
     }
-    std::fs::write(path, content).map_err(|e| ProjectError::Io(e.to_string()))
+    if normalize_eol {
+        atomic_write(path, normalize_line_endings(content).as_bytes())
+    } else {
+        atomic_write(path, content)
+    }
+}
+
+/// Whether the workspace opted into writing notes with LF line endings.
+pub fn repo_normalizes_line_endings(repo_root: &Path) -> bool {
+    read_repo_settings(repo_root)
+        .get("workspaceNormalizeLineEndings")
+        .and_then(serde_json::Value::as_bool)
+        .unwrap_or(false)
+}
+
+/// Content hash of a note, used by editors to detect writes against stale content.
+pub fn hash_note(content: &str) -> String {
+    crate::util::hash::content_hash(content.as_bytes())
+}
+
+/// Hash of the note at `path` as `write_note_checked` sees it; a missing file
+/// hashes as empty content.
+pub fn note_file_hash(path: &Path) -> Result<String, ProjectError> {
+    Ok(hash_note(&read_current_note(path)?))
+}
+
+fn read_current_note(path: &Path) -> Result<String, ProjectError> {
+    if path.exists() {
+        std::fs::read_to_string(path).map_err(|e| ProjectError::Io(e.to_string()))
+    } else {
+        Ok(String::new())
+    }
+}
+
+/// Convert CRLF line endings to LF so note hashes match across platforms.
+pub fn normalize_line_endings(content: &str) -> std::borrow::Cow<'_, str> {
+    if content.contains("\r\n") {
+        std::borrow::Cow::Owned(content.replace("\r\n", "\n"))
+    } else {
+        std::borrow::Cow::Borrowed(content)
+    }
+}
+
+/// Write a note only if the file on disk still matches `base_hash`.
+///
+/// When `base_hash` is `None` this behaves like [`write_note`]. A missing file
+/// hashes as empty content so new notes can be created with `hash_note("")`.
+pub fn write_note_checked(
+    path: &Path,
+    content: &str,
+    base_hash: Option<&str>,
+    normalize_eol: bool,
+) -> Result<(), ProjectError> {
+    if let Some(base_hash) = base_hash {
+        let current_content = read_current_note(path)?;
+        if hash_note(&current_content) != base_hash {
+            return Err(ProjectError::NoteConflict { current_content });
+        }
+    }
+    write_note(path, content, normalize_eol)
 }
 
 pub fn get_note_lock(
@@ -2430,6 +3130,8 @@ const GIT_STATE_DIR: &str = ".git/cutready";
 
 const REPO_SETTINGS_FILE: &str = ".cutready/settings.json";
 
+const LLM_CONFIG_FILE: &str = "llm.json";
+
 /// Resolve the per-project state directory inside .git/cutready/.
 /// For single-project repos: `.git/cutready/`
 /// For multi-project repos: `.git/cutready/<project-name>/`
@@ -2567,6 +3269,65 @@ pub fn write_sidebar_order(
     std::fs::write(&path, data).map_err(|e| ProjectError::Io(e.to_string()))
 }
 
+/// Move one entry within its sidebar section, clamping `to_index` to the
+/// section bounds.
+pub fn move_sidebar_item(
+    order: &mut SidebarOrder,
+    id: &str,
+    to_index: usize,
+) -> Result<(), ProjectError> {
+    let (section, from) = [
+        &mut order.storyboards,
+        &mut order.sketches,
+        &mut order.notes,
+    ]
+    .into_iter()
+    .find_map(|section| {
+        let from = section.iter().position(|entry| entry == id)?;
+        Some((section, from))
+    })
+    .ok_or_else(|| ProjectError::NotFound(id.to_string()))?;
+    let entry = section.remove(from);
+    let to = to_index.min(section.len());
+    section.insert(to, entry);
+    Ok(())
+}
+
+/// Read the project's pinned LLM configuration, if one was saved.
+pub fn read_llm_config(repo_root: &Path, project_root: &Path) -> Option<LlmConfig> {
+    let path = git_state_dir(repo_root, project_root).join(LLM_CONFIG_FILE);
+    let data = std::fs::read_to_string(path).ok()?;
+    serde_json::from_str(&data).ok()
+}
+
+/// Pin an LLM configuration to the project, or clear it with `None`.
+///
+/// Stored in .git/cutready/ so API keys never end up in snapshots. Bearer
+/// tokens are short-lived and are not persisted.
+pub fn write_llm_config(
+    repo_root: &Path,
+    project_root: &Path,
+    config: Option<&LlmConfig>,
+) -> Result<(), ProjectError> {
+    let state_dir = git_state_dir(repo_root, project_root);
+    let path = state_dir.join(LLM_CONFIG_FILE);
+    let Some(config) = config else {
+        return match std::fs::remove_file(&path) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(ProjectError::Io(e.to_string())),
+        };
+    };
+    let config = LlmConfig {
+        bearer_token: None,
+        ..config.clone()
+    };
+    std::fs::create_dir_all(&state_dir).map_err(|e| ProjectError::Io(e.to_string()))?;
+    let data = serde_json::to_string_pretty(&config)
+        .map_err(|e| ProjectError::Serialize(e.to_string()))?;
+    std::fs::write(&path, data).map_err(|e| ProjectError::Io(e.to_string()))
+}
+
 // ── Per-repo (workspace) settings ─────────────────────────────────
 
 /// Read workspace settings from the repo root. Returns the raw JSON value.
@@ -2594,6 +3355,84 @@ pub fn write_repo_settings(
     std::fs::write(&path, data).map_err(|e| ProjectError::Io(e.to_string()))
 }
 
+// ── Version tags ────────────────────────────────────────────────────
+
+/// Milestone tags, stored as `tag=version_id` lines. Keyed by version id so
+/// they survive switching variations and restoring older versions.
+const VERSION_TAGS_FILE: &str = "tags";
+
+/// A named milestone attached to a saved version.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct VersionTag {
+    pub tag: String,
+    pub version_id: String,
+}
+
+/// List every version tag in the repo, sorted by tag name.
+pub fn list_version_tags(repo_root: &Path) -> Result<Vec<VersionTag>, ProjectError> {
+    let path = repo_root.join(GIT_STATE_DIR).join(VERSION_TAGS_FILE);
+    let data = match std::fs::read_to_string(&path) {
+        Ok(data) => data,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(ProjectError::Io(e.to_string())),
+    };
+    let mut tags: Vec<VersionTag> = data
+        .lines()
+        .filter_map(|line| line.split_once('='))
+        .map(|(tag, version_id)| VersionTag {
+            tag: tag.trim().to_string(),
+            version_id: version_id.trim().to_string(),
+        })
+        .filter(|entry| !entry.tag.is_empty() && !entry.version_id.is_empty())
+        .collect();
+    tags.sort_by(|a, b| a.tag.cmp(&b.tag));
+    Ok(tags)
+}
+
+/// Attach `tag` to a version. Re-using an existing tag name moves it.
+pub fn tag_version(
+    repo_root: &Path,
+    version_id: &str,
+    tag: &str,
+) -> Result<Vec<VersionTag>, ProjectError> {
+    let tag = tag.trim();
+    let version_id = version_id.trim();
+    if tag.is_empty() || tag.contains(['=', '\n', '\r']) {
+        return Err(ProjectError::InvalidTag(format!("{tag:?}")));
+    }
+    if version_id.is_empty() {
+        return Err(ProjectError::InvalidTag("version id is required".into()));
+    }
+
+    let mut tags = list_version_tags(repo_root)?;
+    tags.retain(|entry| entry.tag != tag);
+    tags.push(VersionTag {
+        tag: tag.to_string(),
+        version_id: version_id.to_string(),
+    });
+    tags.sort_by(|a, b| a.tag.cmp(&b.tag));
+    write_version_tags(repo_root, &tags)?;
+    Ok(tags)
+}
+
+/// Remove a tag. Removing a tag that does not exist is not an error.
+pub fn delete_version_tag(repo_root: &Path, tag: &str) -> Result<Vec<VersionTag>, ProjectError> {
+    let mut tags = list_version_tags(repo_root)?;
+    tags.retain(|entry| entry.tag != tag.trim());
+    write_version_tags(repo_root, &tags)?;
+    Ok(tags)
+}
+
+fn write_version_tags(repo_root: &Path, tags: &[VersionTag]) -> Result<(), ProjectError> {
+    let state_dir = repo_root.join(GIT_STATE_DIR);
+    std::fs::create_dir_all(&state_dir).map_err(|e| ProjectError::Io(e.to_string()))?;
+    let data: String = tags
+        .iter()
+        .map(|entry| format!("{}={}\n", entry.tag, entry.version_id))
+        .collect();
+    atomic_write(&state_dir.join(VERSION_TAGS_FILE), data.as_bytes())
+}
+
 // ── Internal helpers ────────────────────────────────────────────────
 
 /// Recursively find files with a given extension, skipping `.git` and hidden dirs.
@@ -2649,6 +3488,15 @@ pub enum ProjectError {
     PathTraversal(String),
     #[error("{0}")]
     Locked(String),
+    #[error("Note changed on disk since it was loaded")]
+    NoteConflict { current_content: String },
+    #[error("Invalid tag: {0}")]
+    InvalidTag(String),
+    #[error(
+        "This project was saved by a newer version of CutReady (manifest schema {found}, \
+         this version supports {supported}). Update CutReady to open it."
+    )]
+    UnsupportedManifest { found: u64, supported: u32 },
 }
 
 #[cfg(test)]
@@ -2666,6 +3514,89 @@ mod tests {
         assert!(root.join(".git").exists());
     }
 
+    #[test]
+    fn adopt_repo_rejects_folders_without_git() {
+        let tmp = TempDir::new().unwrap();
+        let mut context = contract::DraftlineCommandContext::new();
+        assert!(matches!(
+            adopt_repo(tmp.path(), &mut context),
+            Err(ProjectError::NotFound(_))
+        ));
+        assert!(!tmp.path().join(".git").exists());
+    }
+
+    #[test]
+    fn tutorial_template_seeds_storyboard_sketches_and_readme() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path().join("Onboarding");
+
+        let view = create_from_template(&root, ProjectTemplate::Tutorial).unwrap();
+
+        assert_eq!(view.name, "Onboarding");
+        assert_eq!(scan_sketches(&root).unwrap().len(), 3);
+        let storyboard = read_storyboard(&root.join("storyboards/tutorial.sb")).unwrap();
+        assert_eq!(storyboard.sketch_paths().len(), 3);
+        for path in storyboard.sketch_paths() {
+            let sketch = read_sketch(&root.join(path)).unwrap();
+            assert!(!sketch.rows.is_empty());
+        }
+        assert!(root.join("README.md").exists());
+    }
+
+    #[test]
+    fn blank_template_matches_plain_init() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path().join("Empty");
+
+        create_from_template(&root, ProjectTemplate::Blank).unwrap();
+
+        assert!(root.join(".git").exists());
+        assert!(scan_sketches(&root).unwrap().is_empty());
+        assert!(!root.join("README.md").exists());
+    }
+
+    #[test]
+    fn atomic_write_leaves_target_intact_when_write_fails() {
+        let tmp = TempDir::new().unwrap();
+        let target = tmp.path().join("project.json");
+        std::fs::write(&target, "{\"complete\":true}").unwrap();
+
+        let mut temp_seen = None;
+        let err = atomic_write_with(&target, |temp| {
+            temp_seen = Some(temp.to_path_buf());
+            std::fs::write(temp, "{\"comp")?;
+            Err(std::io::Error::other("disk full"))
+        })
+        .unwrap_err();
+
+        assert!(err.to_string().contains("disk full"));
+        assert_eq!(
+            std::fs::read_to_string(&target).unwrap(),
+            "{\"complete\":true}"
+        );
+        let temp = temp_seen.unwrap();
+        assert_ne!(temp, target);
+        assert_eq!(temp.parent(), target.parent());
+        assert!(!temp.exists());
+    }
+
+    #[test]
+    fn atomic_write_replaces_target_with_complete_content() {
+        let tmp = TempDir::new().unwrap();
+        let target = tmp.path().join("intro.sk");
+        std::fs::write(&target, "old").unwrap();
+
+        atomic_write(&target, "new content").unwrap();
+
+        assert_eq!(std::fs::read_to_string(&target).unwrap(), "new content");
+        let leftovers: Vec<_> = std::fs::read_dir(tmp.path())
+            .unwrap()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_name().to_string_lossy().ends_with(".tmp"))
+            .collect();
+        assert!(leftovers.is_empty());
+    }
+
     #[test]
     fn write_and_read_sketch() {
         let tmp = TempDir::new().unwrap();
@@ -2704,6 +3635,62 @@ mod tests {
         assert!(!root.join("broken").exists());
     }
 
+    #[test]
+    fn unversioned_manifest_migrates_and_persists_schema_version() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+        std::fs::create_dir_all(root.join(".cutready")).unwrap();
+        std::fs::write(
+            root.join(".cutready/projects.json"),
+            r#"{"projects": [{"path": "demo", "name": "Demo"}]}"#,
+        )
+        .unwrap();
+        let mut sketch = Sketch::new("Intro");
+        let mut row = crate::models::sketch::PlanningRow::new();
+        row.visual = Some(serde_json::json!({ "type": "scene" }));
+        sketch.rows.push(row);
+        std::fs::create_dir_all(root.join("demo/sketches")).unwrap();
+        std::fs::write(
+            root.join("demo/sketches/intro.sk"),
+            serde_json::to_string(&sketch).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(read_manifest(root).unwrap().schema_version, 0);
+        assert!(migrate_manifest_file(root).unwrap());
+
+        let manifest = read_manifest(root).unwrap();
+        assert_eq!(manifest.schema_version, MANIFEST_SCHEMA_VERSION);
+        assert_eq!(manifest.projects[0].path, "demo");
+        let migrated = read_sketch(&root.join("demo/sketches/intro.sk")).unwrap();
+        assert!(migrated.rows[0].visual.as_ref().unwrap().is_string());
+        assert!(!migrate_manifest_file(root).unwrap());
+    }
+
+    #[test]
+    fn open_repo_refuses_a_manifest_from_a_newer_version() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+        std::fs::create_dir_all(root.join(".cutready")).unwrap();
+        let newer = format!(
+            r#"{{"schema_version": {}, "projects": [{{"path": "demo", "name": "Demo"}}]}}"#,
+            MANIFEST_SCHEMA_VERSION + 1
+        );
+        std::fs::write(root.join(".cutready/projects.json"), &newer).unwrap();
+
+        let err = open_repo(root).unwrap_err();
+        assert!(matches!(
+            err,
+            ProjectError::UnsupportedManifest { found, supported }
+                if found == u64::from(MANIFEST_SCHEMA_VERSION) + 1
+                    && supported == MANIFEST_SCHEMA_VERSION
+        ));
+        assert!(err.to_string().contains("newer version of CutReady"));
+        let on_disk = std::fs::read_to_string(root.join(".cutready/projects.json")).unwrap();
+        assert_eq!(on_disk, newer);
+        assert!(!root.join(".git").exists());
+    }
+
     #[test]
     fn inspect_missing_manifest_preserves_legacy_root_fallback() {
         let tmp = TempDir::new().unwrap();
@@ -2806,6 +3793,7 @@ mod tests {
         write_manifest(
             root,
             &ProjectManifest {
+                schema_version: MANIFEST_SCHEMA_VERSION,
                 projects: vec![
                     ProjectEntry {
                         path: "valid".into(),
@@ -2860,6 +3848,7 @@ mod tests {
         write_manifest(
             root,
             &ProjectManifest {
+                schema_version: MANIFEST_SCHEMA_VERSION,
                 projects: vec![ProjectEntry {
                     path: "listed".into(),
                     name: "Listed".into(),
@@ -2887,6 +3876,112 @@ mod tests {
         );
     }
 
+    #[test]
+    fn export_sketch_images_zip_names_steps_by_row() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+        std::fs::create_dir_all(root.join(".cutready/screenshots")).unwrap();
+        std::fs::write(root.join(".cutready/screenshots/a.png"), b"first").unwrap();
+        std::fs::write(root.join(".cutready/screenshots/b.jpg"), b"third").unwrap();
+        let mut sketch = Sketch::new("Walkthrough");
+        for screenshot in [
+            Some(".cutready/screenshots/a.png"),
+            None,
+            Some(".cutready/screenshots/b.jpg"),
+        ] {
+            let mut row = crate::models::sketch::PlanningRow::new();
+            row.screenshot = screenshot.map(str::to_string);
+            sketch.rows.push(row);
+        }
+        write_sketch(&sketch, &root.join("walkthrough.sk"), root).unwrap();
+        let out = root.join("images.zip");
+
+        let export = export_sketch_images_zip(root, "walkthrough.sk", &out).unwrap();
+
+        assert_eq!(export.written, 2);
+        assert_eq!(export.skipped_rows, vec![2]);
+        let mut archive = zip::ZipArchive::new(std::fs::File::open(&out).unwrap()).unwrap();
+        assert_eq!(archive.len(), 2);
+        let mut names: Vec<_> = archive.file_names().map(str::to_string).collect();
+        names.sort();
+        assert_eq!(names, vec!["step_1.png", "step_3.jpg"]);
+        let mut third = String::new();
+        std::io::Read::read_to_string(&mut archive.by_name("step_3.jpg").unwrap(), &mut third)
+            .unwrap();
+        assert_eq!(third, "third");
+    }
+
+    #[test]
+    fn copy_sketch_between_copies_screenshots_and_clears_missing() {
+        let src = TempDir::new().unwrap();
+        let dst = TempDir::new().unwrap();
+        let shots = src.path().join(".cutready/screenshots");
+        std::fs::create_dir_all(&shots).unwrap();
+        std::fs::write(shots.join("login.png"), b"source login").unwrap();
+        std::fs::create_dir_all(dst.path().join(".cutready/screenshots")).unwrap();
+        std::fs::write(
+            dst.path().join(".cutready/screenshots/login.png"),
+            b"unrelated",
+        )
+        .unwrap();
+
+        let mut sketch = Sketch::new("Login");
+        for screenshot in [
+            ".cutready/screenshots/login.png",
+            ".cutready/screenshots/gone.png",
+        ] {
+            let mut row = crate::models::sketch::PlanningRow::new();
+            row.screenshot = Some(screenshot.into());
+            sketch.rows.push(row);
+        }
+        write_sketch(&sketch, &src.path().join("flows/login.sk"), src.path()).unwrap();
+
+        let copied = copy_sketch_between(src.path(), dst.path(), "flows/login.sk").unwrap();
+        assert_eq!(copied, "flows/login.sk");
+
+        let sketch = read_sketch(&dst.path().join(&copied)).unwrap();
+        let first = sketch.rows[0].screenshot.as_deref().unwrap();
+        assert_eq!(first, ".cutready/screenshots/login-2.png");
+        assert_eq!(
+            std::fs::read(dst.path().join(first)).unwrap(),
+            b"source login"
+        );
+        assert!(sketch.rows[1].screenshot.is_none());
+        assert!(copy_sketch_between(src.path(), dst.path(), "flows/login.sk").is_err());
+    }
+
+    #[test]
+    fn project_stats_counts_documents_and_bytes() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+        write_sketch(&Sketch::new("One"), &root.join("sketches/one.sk"), root).unwrap();
+        write_sketch(&Sketch::new("Two"), &root.join("two.sk"), root).unwrap();
+        write_note(&root.join("notes/intro.md"), "# Intro", false).unwrap();
+        std::fs::create_dir_all(root.join(".cutready/screenshots")).unwrap();
+        std::fs::write(root.join(".cutready/screenshots/a.png"), [0u8; 10]).unwrap();
+        std::fs::write(root.join(".cutready/screenshots/b.png"), [0u8; 5]).unwrap();
+        std::fs::create_dir_all(root.join(".git/objects")).unwrap();
+        std::fs::write(root.join(".git/objects/pack"), [0u8; 7]).unwrap();
+
+        let stats = project_stats(root, root).unwrap();
+
+        assert_eq!(stats.sketch_count, 2);
+        assert_eq!(stats.note_count, 1);
+        assert_eq!(stats.storyboard_count, 0);
+        assert_eq!(stats.screenshot_bytes, 15);
+        assert_eq!(stats.git_bytes, 7);
+        assert!(stats.total_bytes >= stats.screenshot_bytes + stats.git_bytes);
+        assert_eq!(stats.total_bytes, directory_size(root).unwrap());
+        assert_eq!(stats.version_count, 0);
+
+        let nested = project_stats(root, &root.join("sketches")).unwrap();
+        assert_eq!(nested.git_bytes, 7);
+        assert_eq!(
+            nested.total_bytes,
+            directory_size(&root.join("sketches")).unwrap()
+        );
+    }
+
     #[test]
     fn delete_sketch_removes_file() {
         let tmp = TempDir::new().unwrap();
@@ -3111,6 +4206,7 @@ mod tests {
         write_note(
             &root.join("notes.md"),
             r#"before ![](.cutready/screenshots/old.png "title") after <img src=".cutready/screenshots/old.png">"#,
+            false,
         )
         .unwrap();
 
@@ -3211,6 +4307,55 @@ mod tests {
         assert!(!get_note_lock(root, "notes/a.md").unwrap().locked);
     }
 
+    #[test]
+    fn write_note_checked_accepts_matching_base_hash() {
+        let tmp = TempDir::new().unwrap();
+        let note = tmp.path().join("draft.md");
+        std::fs::write(&note, "Original").unwrap();
+
+        let base = hash_note("Original");
+        write_note_checked(&note, "Edited", Some(&base), false).unwrap();
+
+        assert_eq!(read_note(&note).unwrap(), "Edited");
+    }
+
+    #[test]
+    fn write_note_checked_rejects_stale_base_hash() {
+        let tmp = TempDir::new().unwrap();
+        let note = tmp.path().join("draft.md");
+        std::fs::write(&note, "Changed elsewhere").unwrap();
+
+        let base = hash_note("Original");
+        let err = write_note_checked(&note, "Edited", Some(&base), false).unwrap_err();
+
+        match err {
+            ProjectError::NoteConflict { current_content } => {
+                assert_eq!(current_content, "Changed elsewhere");
+            }
+            other => panic!("expected conflict, got {other:?}"),
+        }
+        assert_eq!(read_note(&note).unwrap(), "Changed elsewhere");
+    }
+
+    #[test]
+    fn write_note_checked_normalizes_crlf_when_enabled() {
+        let tmp = TempDir::new().unwrap();
+        let note = tmp.path().join("draft.md");
+
+        write_note_checked(&note, "# Title\r\n\r\nBody\r\n", None, true).unwrap();
+        let first = std::fs::read_to_string(&note).unwrap();
+        assert_eq!(first, "# Title\n\nBody\n");
+
+        write_note_checked(&note, "# Title\n\nBody\n", None, true).unwrap();
+        assert_eq!(hash_note(&read_note(&note).unwrap()), hash_note(&first));
+
+        write_note_checked(&note, "a\r\nb", None, false).unwrap();
+        assert_eq!(read_note(&note).unwrap(), "a\r\nb");
+
+        write_note(&note, "a\r\nb", true).unwrap();
+        assert_eq!(read_note(&note).unwrap(), "a\nb");
+    }
+
     #[test]
     fn delete_note_rejects_locked_note() {
         let tmp = TempDir::new().unwrap();
@@ -3289,6 +4434,43 @@ mod tests {
         assert_eq!(summaries[0].title, "Visible");
     }
 
+    #[test]
+    fn scan_sketches_tree_keeps_nested_folders() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+        let sketches = root.join("sketches");
+
+        write_sketch(&Sketch::new("Intro"), &sketches.join("intro.sk"), root).unwrap();
+        write_sketch(
+            &Sketch::new("Login"),
+            &sketches.join("flows").join("login.sk"),
+            root,
+        )
+        .unwrap();
+        write_sketch(
+            &Sketch::new("MFA"),
+            &sketches.join("flows").join("auth").join("mfa.sk"),
+            root,
+        )
+        .unwrap();
+        write_sketch(&Sketch::new("Outside"), &root.join("outside.sk"), root).unwrap();
+
+        let paths: Vec<String> = scan_sketches_tree(root)
+            .unwrap()
+            .into_iter()
+            .map(|s| s.path)
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                "sketches/flows/auth/mfa.sk",
+                "sketches/flows/login.sk",
+                "sketches/intro.sk",
+            ]
+        );
+        assert_eq!(scan_sketches(root).unwrap().len(), 4);
+    }
+
     #[test]
     fn scan_sketches_empty_dir() {
         let tmp = TempDir::new().unwrap();
@@ -3308,6 +4490,61 @@ mod tests {
         assert_eq!(loaded.title, "Full Demo");
     }
 
+    #[test]
+    fn validate_storyboard_reports_missing_sketches() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+        write_sketch(&Sketch::new("Intro"), &root.join("intro.sk"), root).unwrap();
+
+        let mut sb = Storyboard::new("Demo");
+        sb.items = vec![
+            StoryboardItem::SketchRef {
+                path: "intro.sk".into(),
+            },
+            StoryboardItem::Section {
+                title: "Flows".into(),
+                description: String::new(),
+                sketches: vec!["flows/deleted.sk".into()],
+            },
+        ];
+
+        assert_eq!(validate_storyboard(root, &sb), vec!["flows/deleted.sk"]);
+    }
+
+    #[test]
+    fn storyboard_to_markdown_renders_sections_and_missing_sketches() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+        let mut intro = Sketch::new("Intro");
+        intro.rows = vec![crate::models::sketch::PlanningRow {
+            time: "~30s".into(),
+            narrative: "Welcome | hello\nSecond line".into(),
+            demo_actions: "Open the app".into(),
+            ..crate::models::sketch::PlanningRow::new()
+        }];
+        write_sketch(&intro, &root.join("intro.sk"), root).unwrap();
+        write_sketch(&Sketch::new("Login"), &root.join("login.sk"), root).unwrap();
+
+        let mut sb = Storyboard::new("Full Demo");
+        sb.description = "The whole flow.".into();
+        sb.items = vec![
+            StoryboardItem::SketchRef {
+                path: "intro.sk".into(),
+            },
+            StoryboardItem::Section {
+                title: "Auth".into(),
+                description: String::new(),
+                sketches: vec!["login.sk".into(), "gone.sk".into()],
+            },
+        ];
+
+        let md = storyboard_to_markdown(root, &sb).unwrap();
+        assert!(md.starts_with("# Full Demo\n\nThe whole flow.\n\n## Intro\n"));
+        assert!(md.contains("| ~30s | Welcome \\| hello<br>Second line | Open the app |  |"));
+        assert!(md.contains("## Auth\n\n### Login\n"));
+        assert!(md.contains("(missing: gone.sk)"));
+    }
+
     #[test]
     fn scan_storyboards_finds_all() {
         let tmp = TempDir::new().unwrap();
@@ -3366,6 +4603,27 @@ mod tests {
         assert!(safe_resolve(root, "/etc/passwd").is_err());
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn safe_resolve_rejects_symlink_escapes() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path().join("project");
+        let outside = tmp.path().join("outside");
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::create_dir_all(&outside).unwrap();
+        std::fs::write(outside.join("secret.txt"), "secret").unwrap();
+        std::os::unix::fs::symlink(&outside, root.join("linked")).unwrap();
+
+        assert!(matches!(
+            safe_resolve(&root, "linked/secret.txt"),
+            Err(ProjectError::PathTraversal(_))
+        ));
+        assert!(matches!(
+            safe_resolve(&root, "linked/new.txt"),
+            Err(ProjectError::PathTraversal(_))
+        ));
+    }
+
     // ── extract_screenshot_refs tests ─────────────────────────
 
     #[test]
@@ -3821,6 +5079,95 @@ Some text
         );
     }
 
+    #[test]
+    fn move_sidebar_item_repositions_single_entry() {
+        let mut order = SidebarOrder {
+            storyboards: vec!["main.sb".into()],
+            sketches: vec!["a.sk".into(), "b.sk".into(), "c.sk".into()],
+            notes: vec![],
+        };
+
+        move_sidebar_item(&mut order, "c.sk", 0).unwrap();
+        assert_eq!(order.sketches, vec!["c.sk", "a.sk", "b.sk"]);
+
+        move_sidebar_item(&mut order, "c.sk", 99).unwrap();
+        assert_eq!(order.sketches, vec!["a.sk", "b.sk", "c.sk"]);
+
+        move_sidebar_item(&mut order, "a.sk", 1).unwrap();
+        assert_eq!(order.sketches, vec!["b.sk", "a.sk", "c.sk"]);
+        assert_eq!(order.storyboards, vec!["main.sb"]);
+
+        assert!(matches!(
+            move_sidebar_item(&mut order, "missing.sk", 0),
+            Err(ProjectError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn llm_config_round_trips_and_redacts_key() {
+        use crate::engine::agent::llm::{LlmProvider, ProjectLlmConfigView};
+
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+        std::fs::create_dir(root.join(".git")).unwrap();
+        assert!(read_llm_config(root, root).is_none());
+
+        let config = LlmConfig {
+            provider: LlmProvider::Openai,
+            endpoint: String::new(),
+            api_key: "sk-project-secret".into(),
+            model: "gpt-4o".into(),
+            bearer_token: Some("short-lived".into()),
+        };
+        write_llm_config(root, root, Some(&config)).unwrap();
+
+        let stored = read_llm_config(root, root).unwrap();
+        assert_eq!(stored.api_key, "sk-project-secret");
+        assert_eq!(stored.model, "gpt-4o");
+        assert!(stored.bearer_token.is_none());
+
+        let view = ProjectLlmConfigView::from(&stored);
+        assert!(view.has_key);
+        let json = serde_json::to_string(&view).unwrap();
+        assert!(!json.contains("sk-project-secret"));
+
+        write_llm_config(root, root, None).unwrap();
+        assert!(read_llm_config(root, root).is_none());
+    }
+
+    #[test]
+    fn version_tags_round_trip_and_move() {
+        let tmp = TempDir::new().unwrap();
+        let root = tmp.path();
+        assert!(list_version_tags(root).unwrap().is_empty());
+
+        tag_version(root, "aaaa", "v1-shipped").unwrap();
+        tag_version(root, "bbbb", "beta").unwrap();
+        let tags = tag_version(root, "cccc", "v1-shipped").unwrap();
+        assert_eq!(
+            tags,
+            vec![
+                VersionTag {
+                    tag: "beta".into(),
+                    version_id: "bbbb".into(),
+                },
+                VersionTag {
+                    tag: "v1-shipped".into(),
+                    version_id: "cccc".into(),
+                },
+            ]
+        );
+        assert_eq!(
+            std::fs::read_to_string(root.join(".git/cutready/tags")).unwrap(),
+            "beta=bbbb\nv1-shipped=cccc\n"
+        );
+
+        let tags = delete_version_tag(root, "beta").unwrap();
+        assert_eq!(tags.len(), 1);
+        assert_eq!(list_version_tags(root).unwrap(), tags);
+        assert!(tag_version(root, "aaaa", "bad=name").is_err());
+    }
+
     #[test]
     fn git_state_dir_multi_project() {
         let repo = PathBuf::from("/repo");
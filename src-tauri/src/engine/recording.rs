@@ -1047,6 +1047,9 @@ pub struct RecordingAssetRef {
     /// Path relative to the take directory.
     pub path: String,
     pub status: RecordingAssetStatus,
+    /// Last ffprobe result for this file, set by `probe_recording_asset`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub probe: Option<RecordingProbe>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -1082,6 +1085,179 @@ pub struct RecordingTake {
     pub markers: Vec<RecordingMarker>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TrackKind {
+    Video,
+    Audio,
+    Other,
+}
+
+/// One stream reported by ffprobe for a media file.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TrackInfo {
+    /// Stream index within the container.
+    pub index: u32,
+    pub kind: TrackKind,
+    pub codec: Option<String>,
+    /// Stream `title` tag, when the producer set one (e.g. "Mic", "System").
+    pub title: Option<String>,
+}
+
+/// Duration and stream layout of a take's media file.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RecordingProbe {
+    pub duration_ms: u64,
+    pub tracks: Vec<TrackInfo>,
+}
+
+/// Probe a media file with ffprobe, returning its duration and every stream.
+pub fn probe(path: &Path) -> anyhow::Result<(u64, Vec<TrackInfo>)> {
+    if !path.is_file() {
+        anyhow::bail!("Recording file does not exist: {}", path.display());
+    }
+    if let Err(err) = ffmpeg::resolve_ffprobe() {
+        anyhow::bail!("{err}. Install FFmpeg (it includes ffprobe) to read recording tracks.");
+    }
+
+    let output = ffmpeg::run_ffprobe([
+        OsStr::new("-v"),
+        OsStr::new("error"),
+        OsStr::new("-show_format"),
+        OsStr::new("-show_streams"),
+        OsStr::new("-of"),
+        OsStr::new("json"),
+        path.as_os_str(),
+    ])?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "FFprobe failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    parse_ffprobe_streams(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// The metadata file that owns a recording asset, and the asset's path
+/// relative to that file's directory.
+#[derive(Debug, PartialEq, Eq)]
+enum ProbeTarget {
+    Take { sidecar: PathBuf, asset: String },
+    Import { manifest: PathBuf, asset: String },
+}
+
+/// Probe a take or import asset and store the result with that asset: on its
+/// `RecordingAssetRef` in `take.json`, or in the import's `manifest.json`.
+pub fn probe_recording_asset(
+    project_root: &Path,
+    relative_path: &str,
+) -> anyhow::Result<RecordingProbe> {
+    let path =
+        project::safe_resolve(project_root, relative_path).map_err(|e| anyhow::anyhow!("{e}"))?;
+    let target = probe_target(project_root, &path).ok_or_else(|| {
+        anyhow::anyhow!("{relative_path} is not part of a recording take or import")
+    })?;
+    let (duration_ms, tracks) = probe(&path)?;
+    let result = RecordingProbe {
+        duration_ms,
+        tracks,
+    };
+    match target {
+        ProbeTarget::Take { sidecar, asset } => {
+            attach_probe(&sidecar, &asset, result.clone())?;
+        }
+        ProbeTarget::Import { manifest, asset } => {
+            crate::engine::video_import::attach_probe(&manifest, &asset, result.clone())?;
+        }
+    }
+    Ok(result)
+}
+
+/// Find the nearest take or import directory above `path`, stopping at the
+/// project root.
+fn probe_target(project_root: &Path, path: &Path) -> Option<ProbeTarget> {
+    let imports_dir = project_root.join(crate::engine::video_import::IMPORTS_DIR);
+    for dir in path.ancestors().skip(1) {
+        if !dir.starts_with(project_root) || dir == project_root {
+            break;
+        }
+        let asset = path
+            .strip_prefix(dir)
+            .ok()?
+            .to_string_lossy()
+            .replace('\\', "/");
+        let sidecar = dir.join("take.json");
+        if sidecar.is_file() {
+            return Some(ProbeTarget::Take { sidecar, asset });
+        }
+        let manifest = dir.join("manifest.json");
+        if dir.starts_with(&imports_dir) && manifest.is_file() {
+            return Some(ProbeTarget::Import { manifest, asset });
+        }
+    }
+    None
+}
+
+fn attach_probe(
+    take_sidecar: &Path,
+    asset_path: &str,
+    result: RecordingProbe,
+) -> anyhow::Result<RecordingTake> {
+    let mut take: RecordingTake = serde_json::from_str(&std::fs::read_to_string(take_sidecar)?)?;
+    let asset = take
+        .assets
+        .iter_mut()
+        .find(|asset| asset.path == asset_path)
+        .ok_or_else(|| anyhow::anyhow!("{asset_path} is not an asset of take {}", take.id))?;
+    asset.probe = Some(result);
+    take.updated_at = Utc::now();
+    write_take_sidecar(take_sidecar, &take)?;
+    Ok(take)
+}
+
+fn parse_ffprobe_streams(json: &str) -> anyhow::Result<(u64, Vec<TrackInfo>)> {
+    let value: serde_json::Value = serde_json::from_str(json)?;
+    let duration_ms = value
+        .pointer("/format/duration")
+        .and_then(|duration| duration.as_str())
+        .and_then(|duration| duration.parse::<f64>().ok())
+        .filter(|seconds| seconds.is_finite() && *seconds >= 0.0)
+        .map(|seconds| (seconds * 1_000.0).round() as u64)
+        .unwrap_or(0);
+
+    let tracks = value
+        .get("streams")
+        .and_then(|streams| streams.as_array())
+        .map(|streams| {
+            streams
+                .iter()
+                .enumerate()
+                .map(|(position, stream)| TrackInfo {
+                    index: stream
+                        .get("index")
+                        .and_then(|index| index.as_u64())
+                        .unwrap_or(position as u64) as u32,
+                    kind: match stream.get("codec_type").and_then(|kind| kind.as_str()) {
+                        Some("video") => TrackKind::Video,
+                        Some("audio") => TrackKind::Audio,
+                        _ => TrackKind::Other,
+                    },
+                    codec: stream
+                        .get("codec_name")
+                        .and_then(|codec| codec.as_str())
+                        .map(str::to_string),
+                    title: stream
+                        .pointer("/tags/title")
+                        .and_then(|title| title.as_str())
+                        .map(str::to_string),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok((duration_ms, tracks))
+}
+
 pub fn create_recording_take(
     project_root: &Path,
     scope: RecordingScope,
@@ -1176,6 +1352,7 @@ pub fn start_recording_capture(
         kind: RecordingAssetKind::Screen,
         path: output_asset_path.to_string(),
         status: RecordingAssetStatus::Planned,
+        probe: None,
     }];
     let camera_process = match camera_output_path.as_ref() {
         Some(camera_path) => match spawn_camera_process(
@@ -1188,6 +1365,7 @@ pub fn start_recording_capture(
                     kind: RecordingAssetKind::Camera,
                     path: "camera.mp4".to_string(),
                     status: RecordingAssetStatus::Planned,
+                    probe: None,
                 });
                 Some(process)
             }
@@ -1200,6 +1378,7 @@ pub fn start_recording_capture(
                     kind: RecordingAssetKind::Camera,
                     path: "camera.mp4".to_string(),
                     status: RecordingAssetStatus::Missing,
+                    probe: None,
                 });
                 None
             }
@@ -1244,6 +1423,7 @@ pub fn start_recording_capture(
                     kind: RecordingAssetKind::SystemAudio,
                     path: "system-audio.wav".to_string(),
                     status: RecordingAssetStatus::Planned,
+                    probe: None,
                 });
                 write_take_sidecar(&take_dir.join("take.json"), &take)?;
                 Some(process)
@@ -1319,6 +1499,7 @@ pub fn stop_recording_capture(mut active: ActiveRecording) -> anyhow::Result<Rec
             } else {
                 RecordingAssetStatus::Missing
             },
+            probe: None,
         });
     }
 
@@ -1331,6 +1512,7 @@ pub fn stop_recording_capture(mut active: ActiveRecording) -> anyhow::Result<Rec
             } else {
                 RecordingAssetStatus::Missing
             },
+            probe: None,
         });
     }
 
@@ -1354,6 +1536,7 @@ pub fn stop_recording_capture(mut active: ActiveRecording) -> anyhow::Result<Rec
             } else {
                 RecordingAssetStatus::Missing
             },
+            probe: None,
         });
     }
 
@@ -1370,6 +1553,7 @@ pub fn stop_recording_capture(mut active: ActiveRecording) -> anyhow::Result<Rec
                     kind: RecordingAssetKind::ScreenProxy,
                     path: "screen-proxy.mp4".to_string(),
                     status: RecordingAssetStatus::LocalOnly,
+                    probe: None,
                 });
             }
             Err(err) => {
@@ -1382,6 +1566,7 @@ pub fn stop_recording_capture(mut active: ActiveRecording) -> anyhow::Result<Rec
                     kind: RecordingAssetKind::ScreenProxy,
                     path: "screen-proxy.mp4".to_string(),
                     status: RecordingAssetStatus::Missing,
+                    probe: None,
                 });
             }
         }
@@ -2447,6 +2632,33 @@ fn ensure_recordings_gitignore(recordings_dir: &Path) -> anyhow::Result<()> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn parse_ffprobe_streams_reads_duration_and_multiple_audio_tracks() {
+        let json = r#"{
+            "streams": [
+                { "index": 0, "codec_type": "video", "codec_name": "h264" },
+                { "index": 1, "codec_type": "audio", "codec_name": "aac", "tags": { "title": "Mic" } },
+                { "index": 2, "codec_type": "audio", "codec_name": "aac", "tags": { "title": "System" } },
+                { "index": 3, "codec_type": "data" }
+            ],
+            "format": { "duration": "12.3456" }
+        }"#;
+
+        let (duration_ms, tracks) = parse_ffprobe_streams(json).unwrap();
+
+        assert_eq!(duration_ms, 12_346);
+        assert_eq!(tracks.len(), 4);
+        assert_eq!(tracks[0].kind, TrackKind::Video);
+        assert_eq!(tracks[0].codec.as_deref(), Some("h264"));
+        let audio_titles: Vec<_> = tracks
+            .iter()
+            .filter(|track| track.kind == TrackKind::Audio)
+            .map(|track| track.title.as_deref())
+            .collect();
+        assert_eq!(audio_titles, vec![Some("Mic"), Some("System")]);
+        assert_eq!(tracks[3].kind, TrackKind::Other);
+    }
+
     fn default_settings() -> RecorderSettings {
         RecorderSettings {
             capture_source: CaptureSource::FullScreen,
@@ -2602,6 +2814,108 @@ mod tests {
         assert_eq!(parsed, take);
     }
 
+    fn sample_probe(duration_ms: u64) -> RecordingProbe {
+        RecordingProbe {
+            duration_ms,
+            tracks: vec![
+                TrackInfo {
+                    index: 0,
+                    kind: TrackKind::Video,
+                    codec: Some("h264".into()),
+                    title: None,
+                },
+                TrackInfo {
+                    index: 1,
+                    kind: TrackKind::Audio,
+                    codec: Some("aac".into()),
+                    title: Some("Mic".into()),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn probe_results_are_stored_per_take_asset() {
+        let temp = tempfile::tempdir().unwrap();
+        let mut take = create_recording_take(
+            temp.path(),
+            RecordingScope::Sketch {
+                path: "intro.sk".into(),
+            },
+            default_settings(),
+        )
+        .unwrap();
+        let sidecar = temp.path().join(&take.metadata_path);
+        for (kind, path) in [
+            (RecordingAssetKind::Screen, "screen.mp4"),
+            (RecordingAssetKind::Camera, "camera.mp4"),
+        ] {
+            take.assets.push(RecordingAssetRef {
+                kind,
+                path: path.into(),
+                status: RecordingAssetStatus::LocalOnly,
+                probe: None,
+            });
+        }
+        write_take_sidecar(&sidecar, &take).unwrap();
+
+        attach_probe(&sidecar, "screen.mp4", sample_probe(4_250)).unwrap();
+        let updated = attach_probe(&sidecar, "camera.mp4", sample_probe(4_100)).unwrap();
+
+        assert_eq!(updated.assets[0].probe, Some(sample_probe(4_250)));
+        assert_eq!(updated.assets[1].probe, Some(sample_probe(4_100)));
+        let parsed: RecordingTake =
+            serde_json::from_str(&std::fs::read_to_string(&sidecar).unwrap()).unwrap();
+        assert_eq!(parsed, updated);
+        assert!(attach_probe(&sidecar, "mic.wav", sample_probe(1)).is_err());
+    }
+
+    #[test]
+    fn probe_targets_resolve_takes_and_imports() {
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path();
+        let take = create_recording_take(
+            root,
+            RecordingScope::Sketch {
+                path: "intro.sk".into(),
+            },
+            default_settings(),
+        )
+        .unwrap();
+        let take_dir = root
+            .join(&take.metadata_path)
+            .parent()
+            .unwrap()
+            .to_path_buf();
+        let import_dir = root
+            .join(crate::engine::video_import::IMPORTS_DIR)
+            .join("video-1");
+        std::fs::create_dir_all(&import_dir).unwrap();
+        std::fs::write(import_dir.join("manifest.json"), "{}").unwrap();
+        std::fs::write(root.join("clip.mp4"), b"").unwrap();
+
+        assert_eq!(
+            probe_target(root, &take_dir.join("screen.mp4")),
+            Some(ProbeTarget::Take {
+                sidecar: take_dir.join("take.json"),
+                asset: "screen.mp4".into(),
+            })
+        );
+        assert_eq!(
+            probe_target(root, &import_dir.join("audio.wav")),
+            Some(ProbeTarget::Import {
+                manifest: import_dir.join("manifest.json"),
+                asset: "audio.wav".into(),
+            })
+        );
+        assert_eq!(probe_target(root, &root.join("clip.mp4")), None);
+
+        let err = probe_recording_asset(root, "clip.mp4").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("not part of a recording take or import"));
+    }
+
     #[test]
     fn create_take_rejects_traversal_scope() {
         let temp = tempfile::tempdir().unwrap();
@@ -4,6 +4,7 @@
 //! speech-to-text provider so media extraction and sketch synthesis can be
 //! validated independently from automatic STT.
 
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 
@@ -12,10 +13,11 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::engine::agent::llm::{self, ChatMessage, LlmConfig};
+use crate::engine::recording::RecordingProbe;
 use crate::engine::{ffmpeg, project};
 use crate::models::sketch::{PlanningRow, Sketch};
 
-const IMPORTS_DIR: &str = ".cutready/recordings/imports";
+pub(crate) const IMPORTS_DIR: &str = ".cutready/recordings/imports";
 const SCREENSHOTS_DIR: &str = ".cutready/screenshots";
 const MAX_SCENE_SECONDS: u64 = 35;
 const MAX_SCENE_CHARS: usize = 520;
@@ -74,6 +76,10 @@ pub struct VideoImportManifest {
     pub analyzer_row_plan: Option<AnalyzerRowPlan>,
     pub scenes: Vec<SceneCandidate>,
     pub llm_refinement: Option<LlmSceneRefinementSummary>,
+    /// ffprobe results for files in the import directory, keyed by their path
+    /// relative to it.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub probes: BTreeMap<String, RecordingProbe>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -413,7 +419,7 @@ where
         planning_context.as_ref(),
     );
     let note_abs = project::safe_resolve(project_root, note_relative_path)?;
-    project::write_note(&note_abs, &note)?;
+    project::write_note(&note_abs, &note, false)?;
 
     let manifest = VideoImportManifest {
         schema_version: 1,
@@ -430,6 +436,7 @@ where
         analyzer_row_plan: llm_refinement.1.clone(),
         scenes: scenes.clone(),
         llm_refinement: llm_refinement.0.clone(),
+        probes: BTreeMap::new(),
     };
     let manifest_rel_path = format!("{import_rel_dir}/manifest.json");
     let manifest_abs = project::safe_resolve(project_root, &manifest_rel_path)?;
@@ -467,6 +474,20 @@ where
     })
 }
 
+/// Record an ffprobe result for `asset_path` (relative to the import
+/// directory) in the import's `manifest.json`.
+pub(crate) fn attach_probe(
+    manifest_path: &Path,
+    asset_path: &str,
+    result: RecordingProbe,
+) -> anyhow::Result<VideoImportManifest> {
+    let mut manifest: VideoImportManifest =
+        serde_json::from_str(&std::fs::read_to_string(manifest_path)?)?;
+    manifest.probes.insert(asset_path.to_string(), result);
+    std::fs::write(manifest_path, serde_json::to_string_pretty(&manifest)?)?;
+    Ok(manifest)
+}
+
 fn build_summary_note(
     title: &str,
     video_path: &Path,
@@ -1464,7 +1485,7 @@ fn analyzer_notes(
     }
 }
 
-fn extract_json_object(text: &str) -> anyhow::Result<&str> {
+pub(crate) fn extract_json_object(text: &str) -> anyhow::Result<&str> {
     let trimmed = text.trim();
     if trimmed.starts_with('{') && trimmed.ends_with('}') {
         return Ok(trimmed);
@@ -1826,6 +1847,48 @@ This is a short-form WebVTT cue.
         assert!(!import_dir.join("audio.wav").exists());
     }
 
+    #[test]
+    fn attach_probe_records_results_per_import_file() {
+        let temp = tempfile::tempdir().unwrap();
+        let manifest_path = temp.path().join("manifest.json");
+        let manifest = serde_json::json!({
+            "schema_version": 1,
+            "import_id": "video-1",
+            "source_video": "/videos/demo.mp4",
+            "sidecar_transcript": "/videos/demo.srt",
+            "audio_path": null,
+            "sketch_path": "demo.sk",
+            "note_path": "demo.md",
+            "imported_at": "2026-01-01T00:00:00Z",
+            "transcript_segments": [],
+            "heuristic_evidence": {
+                "max_sketch_video_duration_ms": 0,
+                "segments": [],
+                "heuristic_scenes": [],
+                "candidate_boundaries": [],
+                "candidate_frames": []
+            },
+            "planning_context": null,
+            "analyzer_row_plan": null,
+            "scenes": [],
+            "llm_refinement": null
+        });
+        std::fs::write(&manifest_path, manifest.to_string()).unwrap();
+        let probe = |duration_ms| RecordingProbe {
+            duration_ms,
+            tracks: Vec::new(),
+        };
+
+        attach_probe(&manifest_path, "audio.wav", probe(1_000)).unwrap();
+        let updated = attach_probe(&manifest_path, "screen.mp4", probe(2_000)).unwrap();
+
+        assert_eq!(updated.probes.len(), 2);
+        assert_eq!(updated.probes["audio.wav"], probe(1_000));
+        let parsed: VideoImportManifest =
+            serde_json::from_str(&std::fs::read_to_string(&manifest_path).unwrap()).unwrap();
+        assert_eq!(parsed.probes["screen.mp4"], probe(2_000));
+    }
+
     #[test]
     fn builds_summary_note_without_changing_verbatim_sketch_content() {
         let scene = SceneCandidate {
@@ -109,6 +109,13 @@ pub struct RecordingInner {
     pub actions: Vec<CapturedAction>,
     /// The current recording session.
     pub session: Option<models::session::RecordedSession>,
+    /// When the take started or last captured an action, for the idle watchdog.
+    pub last_action_at: Option<std::time::Instant>,
+    /// Whether the current idle stretch has already been reported.
+    pub idle_warned: bool,
+    /// Project root and screenshots folder of the active take, used for
+    /// element-scoped step screenshots.
+    pub screenshots: Option<(std::path::PathBuf, std::path::PathBuf)>,
 }
 
 /// A browser that has been prepared for recording.
@@ -117,8 +124,10 @@ pub struct RecordingInner {
 /// Dropped when the user disconnects.
 pub struct BrowserConnection {
     /// The Playwright sidecar managing the browser.
-    pub sidecar: SidecarManager,
-    /// Which browser channel was used ("chrome", "msedge", "chromium").
+    pub sidecar: Arc<SidecarManager>,
+    /// Which browser channel was used ("chrome", "msedge", "chromium", "firefox").
+    /// A Firefox profile request that fell back to a fresh context reads
+    /// "firefox (fresh context)".
     pub browser_channel: String,
     /// Shared recording state — mutated by both the forwarding task and commands.
     pub recording: Arc<tokio::sync::Mutex<RecordingInner>>,
@@ -138,6 +147,8 @@ pub struct AppState {
     pub current_repo: Mutex<Option<RepoView>>,
     /// The active project within the repo. In single-project mode, root == repo root.
     /// All existing `project_root()` callers read from this field — no changes needed.
+    /// Hold the lock only to read or replace the view: clone the root and drop
+    /// the guard before any disk I/O, so document writes never serialize here.
     pub current_project: Mutex<Option<ProjectView>>,
     /// The prepared browser connection (if any).
     /// Uses `tokio::sync::Mutex` because it's held across await points.
@@ -360,6 +371,111 @@ fn configure_presentation_hotkeys(
     Ok(())
 }
 
+const UI_STORE_FILE: &str = "ui-settings.json";
+const RECORDING_SHORTCUT_KEY: &str = "recording_shortcut";
+/// Resolves to Cmd+Shift+R on macOS and Ctrl+Shift+R elsewhere.
+const DEFAULT_RECORDING_SHORTCUT: &str = "CommandOrControl+Shift+R";
+
+/// The global shortcut currently bound to `toggle-recording`.
+pub struct RecordingShortcutState(pub Mutex<String>);
+
+fn parse_recording_shortcut(
+    hotkey: &str,
+) -> Result<tauri_plugin_global_shortcut::Shortcut, String> {
+    hotkey
+        .trim()
+        .parse::<tauri_plugin_global_shortcut::Shortcut>()
+        .map_err(|error| format!("Invalid shortcut '{hotkey}': {error}"))
+}
+
+fn register_recording_shortcut(app: &tauri::AppHandle, hotkey: &str) -> Result<(), String> {
+    use tauri_plugin_global_shortcut::{GlobalShortcutExt, ShortcutState};
+
+    let shortcut = parse_recording_shortcut(hotkey)?;
+    app.global_shortcut()
+        .on_shortcut(shortcut, |app_handle, _shortcut, event| {
+            if event.state == ShortcutState::Pressed {
+                let _ = app_handle.emit("toggle-recording", ());
+            }
+        })
+        .map_err(|error| format!("Could not register shortcut '{hotkey}': {error}"))
+}
+
+fn saved_recording_shortcut(app: &tauri::AppHandle) -> Option<String> {
+    use tauri_plugin_store::StoreExt;
+
+    let store = app.store(UI_STORE_FILE).ok()?;
+    store
+        .get(RECORDING_SHORTCUT_KEY)
+        .and_then(|value| value.as_str().map(str::to_string))
+}
+
+#[tauri_plugin_auditaur::auditaur_command(skip_all, err)]
+fn get_recording_shortcut(
+    state: tauri::State<'_, RecordingShortcutState>,
+) -> Result<String, String> {
+    state
+        .0
+        .lock()
+        .map(|current| current.clone())
+        .map_err(|error| format!("Recording shortcut lock error: {error}"))
+}
+
+/// Rebind the global recording toggle. The previous binding stays active if the
+/// new shortcut can't be parsed or registered.
+#[tauri_plugin_auditaur::auditaur_command(skip_all, err)]
+fn set_recording_shortcut(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, RecordingShortcutState>,
+    shortcut: String,
+) -> Result<String, String> {
+    use tauri_plugin_global_shortcut::GlobalShortcutExt;
+    use tauri_plugin_store::StoreExt;
+
+    let hotkey = shortcut.trim().to_string();
+    let parsed = parse_recording_shortcut(&hotkey)?;
+    let mut current = state
+        .0
+        .lock()
+        .map_err(|error| format!("Recording shortcut lock error: {error}"))?;
+    let previous = parse_recording_shortcut(&current)?;
+
+    if parsed != previous {
+        let global_shortcuts = app.global_shortcut();
+        if global_shortcuts.is_registered(parsed) {
+            return Err(format!("Shortcut '{hotkey}' is already in use."));
+        }
+        if let Err(error) = global_shortcuts.unregister(previous) {
+            tracing::warn!(
+                target: "cutready::hotkeys",
+                hotkey = %current,
+                error = %error,
+                "Failed to unregister previous recording shortcut",
+            );
+        }
+        if let Err(error) = register_recording_shortcut(&app, &hotkey) {
+            if let Err(restore_error) = register_recording_shortcut(&app, &current) {
+                tracing::warn!(
+                    target: "cutready::hotkeys",
+                    hotkey = %current,
+                    error = %restore_error,
+                    "Failed to restore previous recording shortcut",
+                );
+            }
+            return Err(error);
+        }
+    }
+
+    let store = app.store(UI_STORE_FILE).map_err(|e| e.to_string())?;
+    store.set(
+        RECORDING_SHORTCUT_KEY,
+        serde_json::Value::String(hotkey.clone()),
+    );
+    store.save().map_err(|e| e.to_string())?;
+    *current = hotkey.clone();
+    Ok(hotkey)
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     let auditaur_policy = configure_auditaur_startup();
@@ -373,6 +489,7 @@ pub fn run() {
     if let Err(error) = tracing::subscriber::set_global_default(subscriber) {
         eprintln!("Diagnostics tracing subscriber was not installed: {error}");
     }
+    commands::diagnostics::prune_auditaur_sessions();
 
     fn should_capture_telemetry(metadata: &Metadata<'_>) -> bool {
         let target = metadata.target();
@@ -397,6 +514,9 @@ pub fn run() {
         .manage(auditaur_policy)
         .manage(ProjectLock(tokio::sync::Mutex::new(())))
         .manage(PresentationHotkeyState(Mutex::new(Vec::new())))
+        .manage(RecordingShortcutState(Mutex::new(
+            DEFAULT_RECORDING_SHORTCUT.to_string(),
+        )))
         .manage(commands::screenshot::CaptureState(Mutex::new(None)))
         .manage(commands::screenshot::RecordingCountdownState(Mutex::new(
             None,
@@ -506,14 +626,28 @@ pub fn run() {
                 Modifiers::CONTROL
             };
 
-            let shortcut = Shortcut::new(Some(primary_mod | Modifiers::SHIFT), Code::KeyR);
-
-            app.global_shortcut()
-                .on_shortcut(shortcut, |app_handle, _shortcut, event| {
-                    if event.state == ShortcutState::Pressed {
-                        let _ = app_handle.emit("toggle-recording", ());
-                    }
-                })?;
+            let saved_shortcut = saved_recording_shortcut(app.handle()).filter(|saved| {
+                let registered = register_recording_shortcut(app.handle(), saved);
+                if let Err(error) = &registered {
+                    tracing::warn!(
+                        target: "cutready::hotkeys",
+                        hotkey = %saved,
+                        error = %error,
+                        "Saved recording shortcut is unusable; falling back to default",
+                    );
+                }
+                registered.is_ok()
+            });
+            let recording_shortcut = match saved_shortcut {
+                Some(saved) => saved,
+                None => {
+                    register_recording_shortcut(app.handle(), DEFAULT_RECORDING_SHORTCUT)?;
+                    DEFAULT_RECORDING_SHORTCUT.to_string()
+                }
+            };
+            if let Ok(mut current) = app.state::<RecordingShortcutState>().0.lock() {
+                *current = recording_shortcut;
+            }
 
             let prompter_shortcuts = [
                 (
@@ -557,18 +691,26 @@ pub fn run() {
         })
         .invoke_handler(tauri::generate_handler![
             commands::project::create_project_folder,
+            commands::project::create_project_from_template,
             commands::project::open_project_folder,
+            commands::project::adopt_git_repo,
             commands::project::get_startup_project_path,
             commands::project::get_current_project,
             commands::project::close_project,
             commands::project::get_recent_projects,
             commands::project::add_recent_project,
             commands::project::remove_recent_project,
+            commands::project::prune_recent_projects,
+            commands::project::toggle_pin_recent_project,
             commands::project::get_last_parent_folder,
             commands::project::get_sidebar_order,
             commands::project::set_sidebar_order,
+            commands::project::move_sidebar_item,
             commands::project::get_workspace_state,
             commands::project::set_workspace_state,
+            commands::project::get_project_llm_config,
+            commands::project::set_project_llm_config,
+            commands::project::project_stats,
             commands::project::list_all_files,
             commands::project::preview_rename_project_asset,
             commands::project::rename_project_asset,
@@ -593,13 +735,19 @@ pub fn run() {
             commands::diagnostics::get_diagnostics_policy,
             commands::diagnostics::get_auditaur_diagnostics,
             commands::diagnostics::clear_auditaur_logs,
+            commands::diagnostics::get_log_path,
             configure_presentation_hotkeys,
+            get_recording_shortcut,
+            set_recording_shortcut,
             commands::sketch::create_sketch,
             commands::sketch::update_sketch,
             commands::sketch::update_sketch_title,
+            commands::sketch::transition_sketch_state,
+            commands::sketch::export_sketch_images,
             commands::sketch::delete_sketch,
             commands::sketch::sketch_used_by_storyboards,
             commands::sketch::list_sketches,
+            commands::sketch::list_sketches_tree,
             commands::sketch::get_sketch,
             commands::sketch::set_sketch_lock,
             commands::sketch::set_planning_row_lock,
@@ -609,6 +757,8 @@ pub fn run() {
             commands::sketch::rename_sketch,
             commands::storyboard::create_storyboard,
             commands::storyboard::get_storyboard,
+            commands::storyboard::validate_storyboard,
+            commands::storyboard::export_storyboard_markdown,
             commands::storyboard::update_storyboard,
             commands::storyboard::set_storyboard_lock,
             commands::storyboard::delete_storyboard,
@@ -618,6 +768,8 @@ pub fn run() {
             commands::storyboard::remove_sketch_from_storyboard,
             commands::storyboard::add_section_to_storyboard,
             commands::storyboard::reorder_storyboard_items,
+            commands::storyboard::move_sketch_in_storyboard,
+            commands::storyboard::move_section,
             commands::draftline::open_workspace,
             commands::draftline::clone_workspace,
             commands::draftline::adopt_workspace,
@@ -635,6 +787,10 @@ pub fn run() {
             commands::draftline::get_changes,
             commands::draftline::get_history,
             commands::draftline::get_full_history,
+            commands::draftline::file_history,
+            commands::draftline::list_version_tags,
+            commands::draftline::tag_version,
+            commands::draftline::delete_version_tag,
             commands::draftline::get_history_compaction_candidates,
             commands::draftline::preview_history_cleanup,
             commands::draftline::apply_history_cleanup,
@@ -690,17 +846,32 @@ pub fn run() {
             commands::draftline::clone_from_url,
             commands::interaction::detect_browser_profiles,
             commands::interaction::check_browsers_running,
+            commands::interaction::recorder_readiness,
             commands::interaction::prepare_browser,
+            commands::interaction::get_last_browser_selection,
             commands::interaction::disconnect_browser,
             commands::interaction::start_recording_session,
             commands::interaction::stop_recording_session,
             commands::interaction::get_session_actions,
+            commands::interaction::add_annotation_to_recording,
+            commands::interaction::validate_selector,
+            commands::interaction::list_sessions,
+            commands::interaction::get_session,
+            commands::interaction::link_session_to_sketch,
+            commands::interaction::sessions_for_sketch,
+            commands::interaction::prune_sessions,
+            commands::interaction::filter_session_confidence,
+            commands::interaction::stabilize_session,
+            commands::interaction::merge_sessions,
+            commands::interaction::trim_session,
+            commands::automation::replay_session,
             commands::recording::initialize_recording_storage,
             commands::recording::clear_local_recordings,
             commands::recording::check_ffmpeg_status,
             commands::recording::discover_recording_devices,
             commands::recording::get_recording_platform_capabilities,
             commands::recording::discover_camera_formats,
+            commands::recording::probe_recording,
             commands::recording::get_recording_prompter_script,
             commands::recording::create_recording_take,
             commands::recording::delete_orphaned_narration_assets,
@@ -714,11 +885,14 @@ pub fn run() {
             commands::recording::open_recording_take_folder,
             commands::screenshot::list_monitors,
             commands::screenshot::capture_region,
+            commands::screenshot::capture_sketch_screenshots,
             commands::screenshot::capture_fullscreen,
             commands::screenshot::capture_all_monitors,
+            commands::screenshot::capture_panorama,
             commands::screenshot::open_capture_window,
             commands::screenshot::close_capture_window,
             commands::screenshot::crop_screenshot,
+            commands::screenshot::copy_screenshot_to_clipboard,
             commands::screenshot::get_capture_params,
             commands::screenshot::get_recording_countdown_params,
             commands::screenshot::open_recording_countdown_window,
@@ -738,6 +912,7 @@ pub fn run() {
             commands::note::get_note_lock,
             commands::note::set_note_lock,
             commands::note::update_note,
+            commands::note::get_note_hash,
             commands::note::delete_note,
             commands::note::rename_note,
             commands::note::list_notes,
@@ -756,6 +931,7 @@ pub fn run() {
             commands::video_import::import_video,
             commands::video_import::import_video_with_progress,
             commands::agent::list_models,
+            commands::agent::test_llm_connection,
             commands::agent::agent_chat,
             commands::agent::agent_chat_with_tools,
             commands::agent::cancel_agent_chat_run,
@@ -44,6 +44,32 @@ pub enum SelectorStrategy {
     UiaTreePath(Vec<UiaPathSegment>),
 }
 
+impl SelectorStrategy {
+    /// This selector with surrounding whitespace trimmed, or `None` when
+    /// nothing usable is left.
+    pub fn normalized(&self) -> Option<SelectorStrategy> {
+        let trimmed = |value: &str| {
+            let value = value.trim();
+            (!value.is_empty()).then(|| value.to_string())
+        };
+        match self {
+            SelectorStrategy::CssSelector(v) => trimmed(v).map(SelectorStrategy::CssSelector),
+            SelectorStrategy::XPath(v) => trimmed(v).map(SelectorStrategy::XPath),
+            SelectorStrategy::AccessibilityId(v) => {
+                trimmed(v).map(SelectorStrategy::AccessibilityId)
+            }
+            SelectorStrategy::AccessibilityName(v) => {
+                trimmed(v).map(SelectorStrategy::AccessibilityName)
+            }
+            SelectorStrategy::DataTestId(v) => trimmed(v).map(SelectorStrategy::DataTestId),
+            SelectorStrategy::TextContent(v) => trimmed(v).map(SelectorStrategy::TextContent),
+            SelectorStrategy::UiaTreePath(path) => {
+                (!path.is_empty()).then(|| SelectorStrategy::UiaTreePath(path.clone()))
+            }
+        }
+    }
+}
+
 /// A single atomic demo step. Both the interaction recorder and the
 /// automation engine operate on Actions.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -106,6 +132,36 @@ pub enum Action {
     },
 }
 
+impl Action {
+    /// The element selectors of actions that target an element.
+    pub fn selectors(&self) -> Option<&[SelectorStrategy]> {
+        match self {
+            Action::BrowserClick { selectors }
+            | Action::BrowserType { selectors, .. }
+            | Action::BrowserSelect { selectors, .. }
+            | Action::BrowserWaitForElement { selectors, .. }
+            | Action::NativeClick { selectors }
+            | Action::NativeSelect { selectors, .. }
+            | Action::NativeInvoke { selectors } => Some(selectors),
+            _ => None,
+        }
+    }
+
+    /// Mutable access to the element selectors, see [`Action::selectors`].
+    pub fn selectors_mut(&mut self) -> Option<&mut Vec<SelectorStrategy>> {
+        match self {
+            Action::BrowserClick { selectors }
+            | Action::BrowserType { selectors, .. }
+            | Action::BrowserSelect { selectors, .. }
+            | Action::BrowserWaitForElement { selectors, .. }
+            | Action::NativeClick { selectors }
+            | Action::NativeSelect { selectors, .. }
+            | Action::NativeInvoke { selectors } => Some(selectors),
+            _ => None,
+        }
+    }
+}
+
 /// Metadata attached to every action after recording.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ActionMetadata {
@@ -73,9 +73,15 @@ pub struct ProjectEntry {
     pub description: Option<String>,
 }
 
+/// Current schema version of `.cutready/projects.json`.
+pub const MANIFEST_SCHEMA_VERSION: u32 = 1;
+
 /// The project manifest listing all projects in a multi-project repo.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProjectManifest {
+    /// Manifests written before versioning have no field and read as 0.
+    #[serde(default)]
+    pub schema_version: u32,
     pub projects: Vec<ProjectEntry>,
 }
 
@@ -90,6 +96,12 @@ pub struct RecentProject {
     /// None for single-project repos. Used to restore the active project on re-open.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub last_active_project: Option<String>,
+    /// Whether the folder still exists. Refreshed each time the list is read.
+    #[serde(default)]
+    pub exists: bool,
+    /// Pinned projects stay at the top of the list and are never evicted.
+    #[serde(default)]
+    pub pinned: bool,
 }
 
 #[cfg(test)]
@@ -137,6 +149,7 @@ mod tests {
     #[test]
     fn project_manifest_roundtrip() {
         let manifest = ProjectManifest {
+            schema_version: MANIFEST_SCHEMA_VERSION,
             projects: vec![
                 ProjectEntry {
                     path: "login".into(),
@@ -163,6 +176,8 @@ mod tests {
             path: "/home/user/demo".into(),
             last_opened: Utc::now(),
             last_active_project: None,
+            exists: true,
+            pinned: false,
         };
         let json = serde_json::to_string(&rp).unwrap();
         let parsed: RecentProject = serde_json::from_str(&json).unwrap();
@@ -174,6 +189,8 @@ mod tests {
             path: "/home/user/demos".into(),
             last_opened: Utc::now(),
             last_active_project: Some("login-flow".into()),
+            exists: true,
+            pinned: false,
         };
         let json2 = serde_json::to_string(&rp2).unwrap();
         let parsed2: RecentProject = serde_json::from_str(&json2).unwrap();
@@ -183,5 +200,6 @@ mod tests {
         let old_json = r#"{"path":"/old","last_opened":"2025-01-01T00:00:00Z"}"#;
         let old: RecentProject = serde_json::from_str(old_json).unwrap();
         assert!(old.last_active_project.is_none());
+        assert!(!old.pinned);
     }
 }
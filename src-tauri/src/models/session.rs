@@ -2,7 +2,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use super::action::{Action, ActionMetadata};
+use super::action::{Action, ActionMetadata, SelectorStrategy};
 
 /// Raw output from the interaction recorder — unprocessed captured events.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -29,6 +29,42 @@ impl RecordedSession {
             actions: Vec::new(),
         }
     }
+
+    /// Trim selectors and drop empty ones. Actions that target an element but
+    /// are left without any usable selector are removed. Returns how many
+    /// actions were removed.
+    pub fn sanitize(&mut self) -> usize {
+        let before = self.actions.len();
+        let normalize = |selectors: &mut Vec<SelectorStrategy>| {
+            *selectors = selectors
+                .iter()
+                .filter_map(SelectorStrategy::normalized)
+                .collect();
+        };
+        self.actions.retain_mut(|captured| {
+            normalize(&mut captured.metadata.selector_strategies);
+            match captured.action.selectors_mut() {
+                Some(selectors) => {
+                    normalize(selectors);
+                    !selectors.is_empty()
+                }
+                None => true,
+            }
+        });
+        before - self.actions.len()
+    }
+}
+
+/// Lightweight listing entry for a saved session.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionSummary {
+    pub id: Uuid,
+    pub mode: RecordingMode,
+    #[serde(default)]
+    pub sketch_path: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: Option<DateTime<Utc>>,
+    pub action_count: usize,
 }
 
 /// Whether the session is free-form (continuous) or step-by-step.
@@ -70,7 +106,6 @@ pub enum EventSource {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::action::SelectorStrategy;
 
     #[test]
     fn recorded_session_roundtrip() {
@@ -100,6 +135,51 @@ mod tests {
         assert_eq!(session.actions.len(), parsed.actions.len());
     }
 
+    #[test]
+    fn sanitize_trims_selectors_and_drops_untargetable_actions() {
+        let mut session = RecordedSession::new(RecordingMode::FreeForm);
+        let metadata = ActionMetadata {
+            captured_screenshot: None,
+            selector_strategies: vec![],
+            timestamp_ms: 0,
+            confidence: 1.0,
+            context_snapshot: None,
+        };
+        session.actions.push(CapturedAction {
+            action: Action::BrowserClick {
+                selectors: vec![
+                    SelectorStrategy::CssSelector("   ".into()),
+                    SelectorStrategy::DataTestId("  save ".into()),
+                ],
+            },
+            metadata: metadata.clone(),
+            raw_event: None,
+        });
+        session.actions.push(CapturedAction {
+            action: Action::BrowserClick {
+                selectors: vec![SelectorStrategy::XPath("".into())],
+            },
+            metadata: metadata.clone(),
+            raw_event: None,
+        });
+        session.actions.push(CapturedAction {
+            action: Action::Wait { duration_ms: 100 },
+            metadata,
+            raw_event: None,
+        });
+
+        assert_eq!(session.sanitize(), 1);
+
+        assert_eq!(session.actions.len(), 2);
+        assert_eq!(
+            session.actions[0].action,
+            Action::BrowserClick {
+                selectors: vec![SelectorStrategy::DataTestId("save".into())],
+            }
+        );
+        assert_eq!(session.actions[1].action, Action::Wait { duration_ms: 100 });
+    }
+
     #[test]
     fn session_new_defaults() {
         let session = RecordedSession::new(RecordingMode::StepByStep);
@@ -105,6 +105,37 @@ pub enum SketchState {
     Final,
 }
 
+/// Allowed lifecycle moves: one step forward, one step back, or back to draft.
+const SKETCH_STATE_TRANSITIONS: &[(SketchState, SketchState)] = &[
+    (SketchState::Draft, SketchState::RecordingEnriched),
+    (SketchState::RecordingEnriched, SketchState::Refined),
+    (SketchState::Refined, SketchState::Final),
+    (SketchState::RecordingEnriched, SketchState::Draft),
+    (SketchState::Refined, SketchState::RecordingEnriched),
+    (SketchState::Refined, SketchState::Draft),
+    (SketchState::Final, SketchState::Refined),
+    (SketchState::Final, SketchState::Draft),
+];
+
+impl SketchState {
+    /// Serialized name, as shown to the frontend.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Draft => "draft",
+            Self::RecordingEnriched => "recording_enriched",
+            Self::Refined => "refined",
+            Self::Final => "final",
+        }
+    }
+
+    /// Whether a sketch in this state may move to `to`.
+    pub fn can_transition_to(&self, to: &SketchState) -> bool {
+        SKETCH_STATE_TRANSITIONS
+            .iter()
+            .any(|(from, allowed)| from == self && allowed == to)
+    }
+}
+
 /// Row-level narration audio captured or generated from the narrative text.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct NarrationAsset {
@@ -371,6 +402,12 @@ pub struct SketchSummary {
     pub title: String,
     pub state: SketchState,
     pub row_count: usize,
+    /// Words across the description and every row's narrative and demo actions.
+    #[serde(default)]
+    pub word_count: usize,
+    /// First non-empty row narrative as plain text, for list subtitles.
+    #[serde(default)]
+    pub preview: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -383,12 +420,68 @@ impl SketchSummary {
             title: sketch.title.clone(),
             state: sketch.state.clone(),
             row_count: sketch.rows.len(),
+            word_count: sketch_word_count(sketch),
+            preview: sketch_preview(sketch),
             created_at: sketch.created_at,
             updated_at: sketch.updated_at,
         }
     }
 }
 
+fn sketch_word_count(sketch: &Sketch) -> usize {
+    let description = crate::engine::export::description_text(&sketch.description);
+    let rows = sketch
+        .rows
+        .iter()
+        .map(|row| {
+            row.narrative.split_whitespace().count() + row.demo_actions.split_whitespace().count()
+        })
+        .sum::<usize>();
+    description.split_whitespace().count() + rows
+}
+
+const SKETCH_PREVIEW_MAX_CHARS: usize = 80;
+
+fn sketch_preview(sketch: &Sketch) -> Option<String> {
+    let text = sketch
+        .rows
+        .iter()
+        .map(|row| plain_preview_text(&row.narrative))
+        .find(|text| !text.is_empty())?;
+    if text.chars().count() <= SKETCH_PREVIEW_MAX_CHARS {
+        return Some(text);
+    }
+    let truncated: String = text.chars().take(SKETCH_PREVIEW_MAX_CHARS - 1).collect();
+    Some(format!("{}…", truncated.trim_end()))
+}
+
+/// Flatten markdown narrative to one line, dropping list, heading, and
+/// emphasis markers.
+fn plain_preview_text(markdown: &str) -> String {
+    let text = markdown
+        .lines()
+        .map(|line| {
+            let line = line
+                .trim_start()
+                .trim_start_matches(['#', '>'])
+                .trim_start();
+            let line = line
+                .strip_prefix("- ")
+                .or_else(|| line.strip_prefix("* "))
+                .or_else(|| line.strip_prefix("+ "))
+                .unwrap_or(line);
+            let digits = line.len() - line.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+            let line = match line[digits..].strip_prefix(". ") {
+                Some(rest) if digits > 0 => rest,
+                _ => line,
+            };
+            line.replace(['*', '_', '`'], "")
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
 /// A storyboard — an ordered sequence of sketches with optional sections.
 ///
 /// Stored as a `.sb` file. The file path is the identity (no internal ID).
@@ -522,7 +615,7 @@ impl<'de> Deserialize<'de> for Storyboard {
 }
 
 /// An item in a storyboard's sequence.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum StoryboardItem {
     /// A reference to a sketch by relative path (e.g., "intro.sk").
@@ -627,6 +720,9 @@ pub struct GraphNode {
     /// Author name of this commit.
     #[serde(default)]
     pub author: String,
+    /// Milestone tags attached to this commit.
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 /// Lightweight summary for listing notes (.md files) in the sidebar.
@@ -655,6 +751,14 @@ mod tests {
         assert_eq!(sketch.state, SketchState::Draft);
     }
 
+    #[test]
+    fn sketch_state_transitions_follow_lifecycle() {
+        assert!(SketchState::Draft.can_transition_to(&SketchState::RecordingEnriched));
+        assert!(SketchState::Final.can_transition_to(&SketchState::Draft));
+        assert!(!SketchState::Draft.can_transition_to(&SketchState::Final));
+        assert!(!SketchState::Refined.can_transition_to(&SketchState::Refined));
+    }
+
     #[test]
     fn sketch_lock_cascades_to_rows_and_cells() {
         let mut sketch = Sketch::new("Demo");
@@ -678,6 +782,55 @@ mod tests {
         assert!(!sketch.rows[0].locks.any());
     }
 
+    #[test]
+    fn sketch_summary_counts_words() {
+        let mut sketch = Sketch::new("Counted");
+        sketch.description = serde_json::json!({
+            "root": {"children": [{
+                "type": "paragraph",
+                "children": [{"type": "text", "text": "A short intro"}]
+            }]}
+        });
+        for (narrative, demo_actions) in [("Open the app", "Click Start"), ("Save it", "")] {
+            let mut row = PlanningRow::new();
+            row.narrative = narrative.into();
+            row.demo_actions = demo_actions.into();
+            sketch.rows.push(row);
+        }
+
+        let summary = SketchSummary::from_sketch(&sketch, "counted.sk");
+        assert_eq!(summary.row_count, 2);
+        assert_eq!(summary.word_count, 3 + 3 + 2 + 2);
+    }
+
+    #[test]
+    fn sketch_summary_preview_skips_empty_rows_and_strips_markdown() {
+        let mut sketch = Sketch::new("Preview");
+        for narrative in ["  \n", "- **Open** the `settings` page\n- Pick a theme"] {
+            let mut row = PlanningRow::new();
+            row.narrative = narrative.into();
+            sketch.rows.push(row);
+        }
+
+        let summary = SketchSummary::from_sketch(&sketch, "preview.sk");
+        assert_eq!(
+            summary.preview.as_deref(),
+            Some("Open the settings page Pick a theme")
+        );
+
+        sketch.rows[1].narrative = "word ".repeat(40);
+        let summary = SketchSummary::from_sketch(&sketch, "preview.sk");
+        let preview = summary.preview.unwrap();
+        assert_eq!(preview.chars().count(), 80);
+        assert!(preview.ends_with("…"));
+
+        sketch.rows.truncate(1);
+        assert_eq!(
+            SketchSummary::from_sketch(&sketch, "preview.sk").preview,
+            None
+        );
+    }
+
     #[test]
     fn sketch_roundtrip() {
         let mut sketch = Sketch::new("Test Sketch");
@@ -0,0 +1,29 @@
+//! `document-changed` notifications for backend document writes.
+//!
+//! Editors listen for this event and reload the open document when a write
+//! lands from somewhere other than the editor itself (agent tools, commands).
+
+use tauri::{AppHandle, Emitter, Runtime};
+
+/// Tauri event emitted after a note, sketch, or storyboard is written.
+pub const DOCUMENT_CHANGED_EVENT: &str = "document-changed";
+
+/// Payload for [`DOCUMENT_CHANGED_EVENT`].
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DocumentChanged {
+    /// `"note"`, `"sketch"`, or `"storyboard"`.
+    pub kind: String,
+    /// Project-relative path of the written document.
+    pub path: String,
+}
+
+/// Emit a `document-changed` event. Best-effort: failures are only logged.
+pub fn emit_document_changed<R: Runtime>(app: &AppHandle<R>, kind: &str, path: &str) {
+    let payload = DocumentChanged {
+        kind: kind.to_string(),
+        path: path.replace('\\', "/"),
+    };
+    if let Err(err) = app.emit(DOCUMENT_CHANGED_EVENT, payload) {
+        log::warn!("[document_events] failed to emit {DOCUMENT_CHANGED_EVENT} for {path}: {err}");
+    }
+}
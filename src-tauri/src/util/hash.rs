@@ -0,0 +1,77 @@
+//! Content hashes shared by conflict detection, caching, and deduplication.
+//!
+//! Hashes are lowercase hex BLAKE3 digests. Note base hashes are only compared
+//! against freshly computed ones, but content-hash screenshot names are
+//! persisted: changing the algorithm leaves existing files as they are and
+//! only names new captures differently. Visual filenames keep their own
+//! SHA-256 prefix (see `engine::project::write_visual`).
+
+use serde_json::Value;
+
+/// Hex BLAKE3 of raw bytes.
+pub fn content_hash(bytes: &[u8]) -> String {
+    blake3::hash(bytes).to_hex().to_string()
+}
+
+/// Hash of a JSON value serialized with object keys sorted, so documents that
+/// differ only in field order hash identically.
+#[allow(dead_code)]
+pub fn json_canonical_hash(value: &Value) -> String {
+    let mut canonical = String::new();
+    write_canonical(value, &mut canonical);
+    content_hash(canonical.as_bytes())
+}
+
+fn write_canonical(value: &Value, out: &mut String) {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            out.push('{');
+            for (index, (key, value)) in entries.into_iter().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String(key.clone()).to_string());
+                out.push(':');
+                write_canonical(value, out);
+            }
+            out.push('}');
+        }
+        Value::Array(items) => {
+            out.push('[');
+            for (index, item) in items.iter().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out);
+            }
+            out.push(']');
+        }
+        scalar => out.push_str(&scalar.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn content_hash_is_hex_blake3() {
+        assert_eq!(
+            content_hash(b""),
+            "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262"
+        );
+    }
+
+    #[test]
+    fn canonical_hash_ignores_key_order_but_not_values() {
+        let a = json!({ "title": "Demo", "rows": [{ "time": "5s", "narrative": "Hi" }] });
+        let b = json!({ "rows": [{ "narrative": "Hi", "time": "5s" }], "title": "Demo" });
+        let c = json!({ "rows": [{ "narrative": "Hello", "time": "5s" }], "title": "Demo" });
+
+        assert_eq!(json_canonical_hash(&a), json_canonical_hash(&b));
+        assert_ne!(json_canonical_hash(&a), json_canonical_hash(&c));
+    }
+}
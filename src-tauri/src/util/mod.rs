@@ -1,6 +1,9 @@
 pub mod audio;
+pub mod document_events;
 pub mod ffmpeg;
+pub mod hash;
 pub mod keyboard_hook;
+pub mod richtext;
 pub mod screenshot;
 pub mod sidecar;
 pub mod trace;
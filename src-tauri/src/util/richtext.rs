@@ -0,0 +1,202 @@
+//! Plain-text views of rich-text fields such as sketch descriptions, shared by
+//! search, word counts, exports, and agent context.
+//!
+//! Both Lexical trees (`children`) and ProseMirror-style documents
+//! (`content`) are understood. The walk is deliberately forgiving: legacy
+//! plain strings pass through, and unknown node shapes are searched for
+//! nested text rather than rejected.
+
+use serde_json::Value;
+
+/// Element nodes that flow inline with their siblings instead of starting a
+/// new line.
+const INLINE_ELEMENTS: &[&str] = &["link", "autolink"];
+
+/// Nodes that stand for a line break inside a block.
+const LINE_BREAKS: &[&str] = &["linebreak", "hard_break", "hardBreak"];
+
+/// Flatten a rich-text value to plain text.
+///
+/// Sibling text runs are separated by a space unless one of them already
+/// carries the whitespace, block elements (paragraphs, headings, list items,
+/// ...) end with a newline, and the result is trimmed. `Value::Null` and
+/// values without text flatten to an empty string.
+pub fn to_plain_text(value: &Value) -> String {
+    let mut out = String::new();
+    write_plain_text(value, &mut out);
+    out.trim().to_string()
+}
+
+fn write_plain_text(value: &Value, out: &mut String) {
+    match value {
+        Value::String(text) => push_run(out, text),
+        Value::Array(items) => {
+            for item in items {
+                write_plain_text(item, out);
+            }
+        }
+        Value::Object(map) => {
+            if let Some(Value::String(text)) = map.get("text") {
+                push_run(out, text);
+                return;
+            }
+            let node_type = map.get("type").and_then(Value::as_str);
+            if node_type.is_some_and(|t| LINE_BREAKS.contains(&t)) {
+                out.push('\n');
+                return;
+            }
+            let children = map.get("children").or_else(|| map.get("content"));
+            if let Some(Value::Array(children)) = children {
+                for child in children {
+                    write_plain_text(child, out);
+                }
+                if !node_type.is_some_and(|t| INLINE_ELEMENTS.contains(&t)) {
+                    end_line(out);
+                }
+                return;
+            }
+            for (key, item) in map {
+                if key != "type" {
+                    write_plain_text(item, out);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Append a text run, separating it from the previous run with a space when
+/// neither side has whitespace at the boundary.
+fn push_run(out: &mut String, text: &str) {
+    if text.is_empty() {
+        return;
+    }
+    let touches = out.ends_with(char::is_whitespace) || text.starts_with(char::is_whitespace);
+    if !out.is_empty() && !touches {
+        out.push(' ');
+    }
+    out.push_str(text);
+}
+
+fn end_line(out: &mut String) {
+    if !out.is_empty() && !out.ends_with('\n') {
+        out.push('\n');
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn lexical_tree_flattens_to_lines() {
+        let value = json!({
+            "root": {
+                "type": "root",
+                "format": "",
+                "children": [
+                    {
+                        "type": "heading",
+                        "tag": "h2",
+                        "format": "left",
+                        "children": [{ "type": "text", "text": "Checkout flow", "format": 1 }]
+                    },
+                    {
+                        "type": "paragraph",
+                        "format": "",
+                        "children": [
+                            { "type": "text", "text": "Open the " },
+                            {
+                                "type": "link",
+                                "url": "https://example.com",
+                                "children": [{ "type": "text", "text": "store" }]
+                            },
+                            { "type": "text", "text": " page." },
+                            { "type": "linebreak" },
+                            { "type": "text", "text": "Then pay." }
+                        ]
+                    },
+                    {
+                        "type": "list",
+                        "listType": "bullet",
+                        "children": [
+                            { "type": "listitem", "children": [{ "type": "text", "text": "Card" }] },
+                            { "type": "listitem", "children": [{ "type": "text", "text": "Wallet" }] }
+                        ]
+                    }
+                ]
+            }
+        });
+
+        assert_eq!(
+            to_plain_text(&value),
+            "Checkout flow\nOpen the store page.\nThen pay.\nCard\nWallet"
+        );
+    }
+
+    #[test]
+    fn sibling_runs_without_whitespace_are_spaced() {
+        let value = json!({
+            "root": {
+                "type": "root",
+                "children": [{
+                    "type": "paragraph",
+                    "children": [
+                        { "type": "text", "text": "Show the" },
+                        { "type": "text", "text": "dashboard." }
+                    ]
+                }]
+            }
+        });
+
+        assert_eq!(to_plain_text(&value), "Show the dashboard.");
+        assert_eq!(to_plain_text(&json!(["Intro", "Outro"])), "Intro Outro");
+    }
+
+    #[test]
+    fn content_documents_flatten_to_lines() {
+        let value = json!({
+            "type": "doc",
+            "content": [
+                {
+                    "type": "heading",
+                    "attrs": { "level": 2 },
+                    "content": [{ "type": "text", "text": "Setup" }]
+                },
+                {
+                    "type": "paragraph",
+                    "content": [
+                        { "type": "text", "text": "Sign in" },
+                        { "type": "text", "marks": [{ "type": "bold" }], "text": "first." },
+                        { "type": "hard_break" },
+                        { "type": "text", "text": "Then continue." }
+                    ]
+                },
+                {
+                    "type": "bullet_list",
+                    "content": [
+                        {
+                            "type": "list_item",
+                            "content": [{
+                                "type": "paragraph",
+                                "content": [{ "type": "text", "text": "Card" }]
+                            }]
+                        }
+                    ]
+                }
+            ]
+        });
+
+        assert_eq!(
+            to_plain_text(&value),
+            "Setup\nSign in first.\nThen continue.\nCard"
+        );
+    }
+
+    #[test]
+    fn null_and_plain_strings() {
+        assert_eq!(to_plain_text(&Value::Null), "");
+        assert_eq!(to_plain_text(&json!("  Just text ")), "Just text");
+    }
+}
@@ -1,7 +1,6 @@
 //! Screenshot capture utilities using xcap.
 
 use image::ImageEncoder;
-use std::io::BufWriter;
 use std::path::{Path, PathBuf};
 use xcap::Monitor;
 
@@ -51,6 +50,21 @@ pub fn list_monitors() -> Result<Vec<MonitorInfo>, String> {
     Ok(result)
 }
 
+/// Whether two monitor lists describe a different display layout.
+///
+/// Compares ids, bounds, and the primary flag regardless of order.
+pub fn monitors_changed(previous: &[MonitorInfo], current: &[MonitorInfo]) -> bool {
+    let layout = |monitors: &[MonitorInfo]| {
+        let mut layout: Vec<_> = monitors
+            .iter()
+            .map(|m| (m.id, m.x, m.y, m.width, m.height, m.is_primary))
+            .collect();
+        layout.sort_unstable();
+        layout
+    };
+    layout(previous) != layout(current)
+}
+
 #[derive(Clone, Debug)]
 struct NativeMonitorInfo {
     device_name: String,
@@ -200,13 +214,56 @@ fn screenshot_filename() -> String {
     format!("{ts}_{seq}.jpg")
 }
 
-/// Save an RGBA image as JPEG (quality 95). Much faster than PNG for large screenshots.
-fn save_jpeg(img: &image::RgbaImage, path: &Path) -> Result<(), String> {
+/// JPEG quality used when a workspace doesn't set one.
+pub const DEFAULT_SCREENSHOT_QUALITY: u8 = 90;
+
+/// How a newly captured screenshot is named on disk.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ScreenshotNaming {
+    /// `<timestamp>_<seq>.jpg`. Every capture gets a new file.
+    #[default]
+    Timestamp,
+    /// `<content hash prefix>.jpg` of the encoded bytes. Identical captures share
+    /// one file, so unchanged screenshots don't produce new blobs in version history.
+    ContentHash,
+}
+
+/// How captured screenshots are encoded and named.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScreenshotOptions {
+    /// JPEG quality, clamped to 1–100 when encoding.
+    pub quality: u8,
+    pub naming: ScreenshotNaming,
+}
+
+impl Default for ScreenshotOptions {
+    fn default() -> Self {
+        Self {
+            quality: DEFAULT_SCREENSHOT_QUALITY,
+            naming: ScreenshotNaming::default(),
+        }
+    }
+}
+
+/// Filename for encoded screenshot bytes under the given naming scheme.
+fn screenshot_filename_for(bytes: &[u8], naming: ScreenshotNaming) -> String {
+    match naming {
+        ScreenshotNaming::Timestamp => screenshot_filename(),
+        ScreenshotNaming::ContentHash => {
+            let hash = crate::util::hash::content_hash(bytes);
+            format!("{}.jpg", &hash[..32])
+        }
+    }
+}
+
+/// Encode an RGBA image as JPEG. Much faster than PNG for large screenshots.
+/// `quality` is clamped to 1–100.
+fn encode_jpeg(img: &image::RgbaImage, quality: u8) -> Result<Vec<u8>, String> {
     // JPEG doesn't support alpha — convert RGBA → RGB
     let rgb: image::RgbImage = image::DynamicImage::ImageRgba8(img.clone()).to_rgb8();
-    let file = std::fs::File::create(path).map_err(|e| format!("Failed to create file: {e}"))?;
-    let writer = BufWriter::new(file);
-    let encoder = image::codecs::jpeg::JpegEncoder::new_with_quality(writer, 95);
+    let mut bytes = Vec::new();
+    let encoder =
+        image::codecs::jpeg::JpegEncoder::new_with_quality(&mut bytes, quality.clamp(1, 100));
     encoder
         .write_image(
             rgb.as_raw(),
@@ -214,7 +271,24 @@ fn save_jpeg(img: &image::RgbaImage, path: &Path) -> Result<(), String> {
             rgb.height(),
             image::ExtendedColorType::Rgb8,
         )
-        .map_err(|e| format!("JPEG encode failed: {e}"))
+        .map_err(|e| format!("JPEG encode failed: {e}"))?;
+    Ok(bytes)
+}
+
+/// Encode and write a screenshot into `dir`, returning the project-relative path.
+/// Content-hashed files that already exist are reused rather than rewritten.
+fn save_screenshot(
+    dir: &Path,
+    img: &image::RgbaImage,
+    options: ScreenshotOptions,
+) -> Result<String, String> {
+    let bytes = encode_jpeg(img, options.quality)?;
+    let filename = screenshot_filename_for(&bytes, options.naming);
+    let abs_path = dir.join(&filename);
+    if !(options.naming == ScreenshotNaming::ContentHash && abs_path.exists()) {
+        std::fs::write(&abs_path, &bytes).map_err(|e| format!("Failed to write file: {e}"))?;
+    }
+    Ok(format!(".cutready/screenshots/{filename}"))
 }
 
 /// Capture a region of a monitor and save to the project's screenshot directory.
@@ -226,6 +300,7 @@ pub fn capture_region(
     y: i32,
     width: u32,
     height: u32,
+    options: ScreenshotOptions,
 ) -> Result<String, String> {
     let monitor = find_monitor(monitor_id)?;
 
@@ -243,13 +318,7 @@ pub fn capture_region(
     let cropped = image::imageops::crop_imm(&img, rel_x, rel_y, width, height).to_image();
 
     let dir = screenshots_dir(project_dir)?;
-    let filename = screenshot_filename();
-    let abs_path = dir.join(&filename);
-
-    save_jpeg(&cropped, &abs_path)?;
-
-    let rel_path = format!(".cutready/screenshots/{filename}");
-    Ok(rel_path)
+    save_screenshot(&dir, &cropped, options)
 }
 
 /// Capture multiple monitors in parallel and save to the project's screenshot directory.
@@ -257,6 +326,7 @@ pub fn capture_region(
 pub fn capture_all_monitors(
     project_dir: &Path,
     monitor_ids: &[u32],
+    options: ScreenshotOptions,
 ) -> Result<std::collections::HashMap<u32, String>, String> {
     let dir = screenshots_dir(project_dir)?;
     let all_monitors = Monitor::all().map_err(|e| format!("Failed to enumerate monitors: {e}"))?;
@@ -282,10 +352,7 @@ pub fn capture_all_monitors(
         .map(|(mid, img)| {
             let dir = dir.clone();
             std::thread::spawn(move || -> Result<(u32, String), String> {
-                let filename = screenshot_filename();
-                let abs_path = dir.join(&filename);
-                save_jpeg(&img, &abs_path)?;
-                let rel_path = format!(".cutready/screenshots/{filename}");
+                let rel_path = save_screenshot(&dir, &img, options)?;
                 Ok((mid, rel_path))
             })
         })
@@ -299,21 +366,65 @@ pub fn capture_all_monitors(
     Ok(results)
 }
 
+/// Capture every monitor and stitch them into one image laid out by their
+/// desktop positions. Returns the relative path of the single saved file.
+pub fn capture_panorama(project_dir: &Path, options: ScreenshotOptions) -> Result<String, String> {
+    let monitors = Monitor::all().map_err(|e| format!("Failed to enumerate monitors: {e}"))?;
+    let captures = monitors
+        .iter()
+        .map(|monitor| {
+            let x = monitor.x().map_err(|e| format!("Monitor x error: {e}"))?;
+            let y = monitor.y().map_err(|e| format!("Monitor y error: {e}"))?;
+            let img = monitor
+                .capture_image()
+                .map_err(|e| format!("Capture failed: {e}"))?;
+            Ok((x, y, img))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let panorama = composite_panorama(&captures).ok_or("No monitors to capture")?;
+    let dir = screenshots_dir(project_dir)?;
+    save_screenshot(&dir, &panorama, options)
+}
+
+/// Composite `(x, y, image)` captures onto one canvas spanning their bounding box.
+/// Origins may be negative (monitors left of or above the primary); gaps are black.
+fn composite_panorama(captures: &[(i32, i32, image::RgbaImage)]) -> Option<image::RgbaImage> {
+    let min_x = captures.iter().map(|(x, _, _)| *x as i64).min()?;
+    let min_y = captures.iter().map(|(_, y, _)| *y as i64).min()?;
+    let max_x = captures
+        .iter()
+        .map(|(x, _, img)| *x as i64 + img.width() as i64)
+        .max()?;
+    let max_y = captures
+        .iter()
+        .map(|(_, y, img)| *y as i64 + img.height() as i64)
+        .max()?;
+
+    let mut canvas = image::RgbaImage::from_pixel(
+        (max_x - min_x) as u32,
+        (max_y - min_y) as u32,
+        image::Rgba([0, 0, 0, 255]),
+    );
+    for (x, y, img) in captures {
+        image::imageops::replace(&mut canvas, img, *x as i64 - min_x, *y as i64 - min_y);
+    }
+    Some(canvas)
+}
+
 /// Capture the entire monitor and save to the project's screenshot directory.
-pub fn capture_fullscreen(project_dir: &Path, monitor_id: u32) -> Result<String, String> {
+pub fn capture_fullscreen(
+    project_dir: &Path,
+    monitor_id: u32,
+    options: ScreenshotOptions,
+) -> Result<String, String> {
     let monitor = find_monitor(monitor_id)?;
     let img = monitor
         .capture_image()
         .map_err(|e| format!("Capture failed: {e}"))?;
 
     let dir = screenshots_dir(project_dir)?;
-    let filename = screenshot_filename();
-    let abs_path = dir.join(&filename);
-
-    save_jpeg(&img, &abs_path)?;
-
-    let rel_path = format!(".cutready/screenshots/{filename}");
-    Ok(rel_path)
+    save_screenshot(&dir, &img, options)
 }
 
 /// Crop a region from an existing screenshot image and save as a new file.
@@ -326,18 +437,131 @@ pub fn crop_screenshot(
     y: u32,
     width: u32,
     height: u32,
+    options: ScreenshotOptions,
 ) -> Result<String, String> {
-    let source_abs = project_dir.join(source_rel);
+    let source_abs =
+        crate::engine::project::safe_resolve(project_dir, source_rel).map_err(|e| e.to_string())?;
     let img = image::open(&source_abs).map_err(|e| format!("Failed to open source image: {e}"))?;
 
     let cropped = image::imageops::crop_imm(&img, x, y, width, height).to_image();
 
     let dir = screenshots_dir(project_dir)?;
-    let filename = screenshot_filename();
-    let abs_path = dir.join(&filename);
+    save_screenshot(&dir, &cropped, options)
+}
+
+/// Decode an existing screenshot and place it on the system clipboard as an image.
+/// `source_rel` is the relative path from project root.
+pub fn copy_to_clipboard(project_dir: &Path, source_rel: &str) -> Result<(), String> {
+    let source_abs =
+        crate::engine::project::safe_resolve(project_dir, source_rel).map_err(|e| e.to_string())?;
+    let img = image::open(&source_abs)
+        .map_err(|e| format!("Failed to open source image: {e}"))?
+        .to_rgba8();
+
+    let mut clipboard = arboard::Clipboard::new().map_err(clipboard_error)?;
+    clipboard
+        .set_image(arboard::ImageData {
+            width: img.width() as usize,
+            height: img.height() as usize,
+            bytes: std::borrow::Cow::Owned(img.into_raw()),
+        })
+        .map_err(clipboard_error)
+}
+
+fn clipboard_error(error: arboard::Error) -> String {
+    match error {
+        arboard::Error::ClipboardNotSupported => {
+            "Copying images to the clipboard is not supported on this platform".to_string()
+        }
+        other => format!("Clipboard error: {other}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn noisy_image() -> image::RgbaImage {
+        image::RgbaImage::from_fn(128, 128, |x, y| {
+            let v = ((x * 31 + y * 17) ^ (x * y)) as u8;
+            image::Rgba([v, v.wrapping_mul(3), v.wrapping_add(x as u8), 255])
+        })
+    }
+
+    #[test]
+    fn encode_jpeg_honors_quality() {
+        let img = noisy_image();
+        let low = encode_jpeg(&img, 10).unwrap().len();
+        let high = encode_jpeg(&img, 95).unwrap().len();
+        assert!(low < high, "{low} >= {high}");
+    }
+
+    #[test]
+    fn composite_panorama_places_monitors_by_offset() {
+        let red = image::Rgba([255, 0, 0, 255]);
+        let blue = image::Rgba([0, 0, 255, 255]);
+        let black = image::Rgba([0, 0, 0, 255]);
+        // A 4x2 monitor left of a 2x3 primary, shifted up by one pixel.
+        let left = image::RgbaImage::from_pixel(4, 2, red);
+        let primary = image::RgbaImage::from_pixel(2, 3, blue);
+
+        let canvas = composite_panorama(&[(0, 0, primary), (-4, -1, left)]).unwrap();
+
+        assert_eq!(canvas.dimensions(), (6, 4));
+        assert_eq!(*canvas.get_pixel(0, 0), red);
+        assert_eq!(*canvas.get_pixel(3, 1), red);
+        assert_eq!(*canvas.get_pixel(4, 1), blue);
+        assert_eq!(*canvas.get_pixel(5, 3), blue);
+        assert_eq!(*canvas.get_pixel(0, 2), black);
+        assert_eq!(*canvas.get_pixel(4, 0), black);
+        assert!(composite_panorama(&[]).is_none());
+    }
+
+    #[test]
+    fn content_hash_naming_dedupes_identical_captures() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let img = noisy_image();
+
+        let options = |quality, naming| ScreenshotOptions { quality, naming };
+        let hashed = |quality| options(quality, ScreenshotNaming::ContentHash);
+
+        let first = save_screenshot(tmp.path(), &img, hashed(80)).unwrap();
+        let second = save_screenshot(tmp.path(), &img, hashed(80)).unwrap();
+        let other = save_screenshot(tmp.path(), &img, hashed(40)).unwrap();
 
-    save_jpeg(&cropped, &abs_path)?;
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+        assert_eq!(std::fs::read_dir(tmp.path()).unwrap().count(), 2);
 
-    let rel_path = format!(".cutready/screenshots/{filename}");
-    Ok(rel_path)
+        let a = save_screenshot(tmp.path(), &img, ScreenshotOptions::default()).unwrap();
+        let b = save_screenshot(tmp.path(), &img, ScreenshotOptions::default()).unwrap();
+        assert_ne!(a, b);
+    }
+
+    fn monitor(id: u32, x: i32, width: u32) -> MonitorInfo {
+        MonitorInfo {
+            id,
+            name: format!("Display {id}"),
+            device_name: None,
+            hmonitor: None,
+            dxgi_output_index: None,
+            x,
+            y: 0,
+            width,
+            height: 1080,
+            is_primary: id == 1,
+        }
+    }
+
+    #[test]
+    fn monitors_changed_ignores_order_but_not_layout() {
+        let before = vec![monitor(1, 0, 1920), monitor(2, 1920, 2560)];
+        let reordered = vec![monitor(2, 1920, 2560), monitor(1, 0, 1920)];
+        let unplugged = vec![monitor(1, 0, 1920)];
+        let resized = vec![monitor(1, 0, 1920), monitor(2, 1920, 1280)];
+
+        assert!(!monitors_changed(&before, &reordered));
+        assert!(monitors_changed(&before, &unplugged));
+        assert!(monitors_changed(&before, &resized));
+    }
 }
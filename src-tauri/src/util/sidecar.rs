@@ -4,12 +4,12 @@
 //! observation during interaction recording. Communication happens via
 //! newline-delimited JSON over stdin/stdout.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::path::Path;
 use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
-use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, BufWriter};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWriteExt, BufReader, BufWriter};
 use tokio::process::{Child, ChildStdin, ChildStdout, Command};
 use tokio::sync::{mpsc, oneshot, Mutex};
 use tokio::task::JoinHandle;
@@ -52,6 +52,40 @@ struct SidecarErrorDetail {
 /// Map of pending request IDs to their response channels.
 type PendingMap = HashMap<u64, oneshot::Sender<Result<serde_json::Value, String>>>;
 
+/// Most recent stderr lines, oldest first.
+type StderrBuffer = Arc<std::sync::Mutex<VecDeque<String>>>;
+
+/// How many stderr lines are kept for diagnostics.
+const STDERR_BUFFER_LINES: usize = 50;
+
+/// How long each shutdown phase (quit requests, then process exit) may take
+/// before the sidecar is killed.
+const SHUTDOWN_GRACE: std::time::Duration = std::time::Duration::from_secs(3);
+
+// ── SidecarClient ───────────────────────────────────────────────────────────
+
+/// Request/response access to the sidecar.
+///
+/// Engine code that drives the browser takes `&impl SidecarClient` so it can
+/// be exercised against a stub in tests.
+pub trait SidecarClient {
+    fn request(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> impl std::future::Future<Output = Result<serde_json::Value, String>> + Send;
+}
+
+impl SidecarClient for SidecarManager {
+    fn request(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> impl std::future::Future<Output = Result<serde_json::Value, String>> + Send {
+        SidecarManager::request(self, method, params)
+    }
+}
+
 // ── SidecarManager ──────────────────────────────────────────────────────────
 
 /// Manages the Playwright Node.js sidecar process.
@@ -63,7 +97,9 @@ pub struct SidecarManager {
     stdin: Mutex<BufWriter<ChildStdin>>,
     next_id: Mutex<u64>,
     pending: Arc<Mutex<PendingMap>>,
+    stderr: StderrBuffer,
     _reader_handle: JoinHandle<()>,
+    _stderr_handle: JoinHandle<()>,
 }
 
 impl SidecarManager {
@@ -88,6 +124,7 @@ impl SidecarManager {
 
         let stdin = child.stdin.take().expect("stdin not captured");
         let stdout = child.stdout.take().expect("stdout not captured");
+        let stderr_pipe = child.stderr.take().expect("stderr not captured");
 
         let (event_tx, event_rx) = mpsc::unbounded_channel();
         let pending: Arc<Mutex<PendingMap>> = Arc::new(Mutex::new(HashMap::new()));
@@ -97,12 +134,20 @@ impl SidecarManager {
             Self::reader_loop(stdout, reader_pending, event_tx).await;
         });
 
+        let stderr: StderrBuffer = Arc::default();
+        let stderr_buffer = stderr.clone();
+        let stderr_handle = tokio::spawn(async move {
+            Self::stderr_loop(stderr_pipe, stderr_buffer).await;
+        });
+
         let manager = Self {
             child: Mutex::new(child),
             stdin: Mutex::new(BufWriter::new(stdin)),
             next_id: Mutex::new(1),
             pending,
+            stderr,
             _reader_handle: reader_handle,
+            _stderr_handle: stderr_handle,
         };
 
         Ok((manager, event_rx))
@@ -183,6 +228,33 @@ impl SidecarManager {
         tracing::info!("Sidecar stdout reader ended");
     }
 
+    /// Background task that keeps the last [`STDERR_BUFFER_LINES`] lines the
+    /// sidecar writes to stderr, so failures can report what Node printed.
+    async fn stderr_loop(stderr: impl AsyncRead + Unpin, buffer: StderrBuffer) {
+        let mut lines = BufReader::new(stderr).lines();
+        while let Ok(Some(line)) = lines.next_line().await {
+            if line.trim().is_empty() {
+                continue;
+            }
+            tracing::debug!("Sidecar stderr: {line}");
+            let Ok(mut buffer) = buffer.lock() else {
+                break;
+            };
+            if buffer.len() == STDERR_BUFFER_LINES {
+                buffer.pop_front();
+            }
+            buffer.push_back(line);
+        }
+    }
+
+    /// The most recent lines the sidecar wrote to stderr, oldest first.
+    pub fn recent_stderr(&self) -> Vec<String> {
+        self.stderr
+            .lock()
+            .map(|buffer| buffer.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
     /// Send a request to the sidecar and wait for the response.
     ///
     /// Times out after 30 seconds.
@@ -250,9 +322,30 @@ impl SidecarManager {
     }
 
     /// Shut down the sidecar process.
+    ///
+    /// Asks the sidecar to close its browser and exit so Playwright can tear
+    /// down the browser processes it launched, then kills it if it hasn't
+    /// exited within [`SHUTDOWN_GRACE`].
     pub async fn shutdown(&self) -> anyhow::Result<()> {
+        let _ = tokio::time::timeout(SHUTDOWN_GRACE, async {
+            // Fails harmlessly when no browser is open.
+            let _ = self.request("browser.close", serde_json::json!({})).await;
+            let _ = self.request("shutdown", serde_json::json!({})).await;
+        })
+        .await;
+
         let mut child = self.child.lock().await;
-        let _ = child.kill().await;
+        match tokio::time::timeout(SHUTDOWN_GRACE, child.wait()).await {
+            Ok(Ok(_)) => {}
+            Ok(Err(e)) => {
+                tracing::warn!("Waiting for sidecar exit failed: {e}");
+                let _ = child.kill().await;
+            }
+            Err(_) => {
+                tracing::warn!("Sidecar did not exit after shutdown request; killing it");
+                let _ = child.kill().await;
+            }
+        }
         Ok(())
     }
 }
@@ -275,6 +368,17 @@ mod tests {
         assert!(json.contains("\"method\":\"ping\""));
     }
 
+    #[test]
+    fn shutdown_request_serialization() {
+        let req = SidecarRequest {
+            id: 7,
+            method: "shutdown".to_string(),
+            params: serde_json::json!({}),
+        };
+        let json = serde_json::to_string(&req).unwrap();
+        assert_eq!(json, r#"{"id":7,"method":"shutdown","params":{}}"#);
+    }
+
     #[test]
     fn sidecar_response_deserialization() {
         let json = r#"{"id":1,"result":{"status":"pong"}}"#;
@@ -306,6 +410,24 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn stderr_loop_keeps_the_most_recent_lines() {
+        let output: String = (0..STDERR_BUFFER_LINES + 3)
+            .map(|i| format!("line {i}\n\n"))
+            .collect();
+        let buffer: StderrBuffer = Arc::default();
+
+        SidecarManager::stderr_loop(output.as_bytes(), buffer.clone()).await;
+
+        let lines: Vec<String> = buffer.lock().unwrap().iter().cloned().collect();
+        assert_eq!(lines.len(), STDERR_BUFFER_LINES);
+        assert_eq!(lines[0], "line 3");
+        assert_eq!(
+            lines.last().map(String::as_str),
+            Some(format!("line {}", STDERR_BUFFER_LINES + 2).as_str())
+        );
+    }
+
     #[test]
     fn dispatch_by_json_shape() {
         // Response has "id"
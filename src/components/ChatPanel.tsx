@@ -7,7 +7,7 @@ import { SessionHistoryPanel } from "./SessionHistoryPanel";
 import { ProjectImage } from "./ProjectImage";
 import { projectRelativeScreenshotPath } from "../utils/projectImage";
 
-import { clearSuppressedEditorFlush, suppressEditorFlush, useAppStore } from "../stores/appStore";
+import { clearSuppressedEditorFlush, errorMessage, suppressEditorFlush, useAppStore } from "../stores/appStore";
 import { useAiApplyGateStore } from "../stores/aiApplyGateStore";
 import { useSettings, type AgentPreset } from "../hooks/useSettings";
 import { loadProviderSecrets } from "../hooks/useSecretStore";
@@ -224,6 +224,7 @@ interface AgentChatResult {
 
 const ROW_TARGETED_SKETCH_MUTATION_TOOLS = new Set([
   "update_planning_row",
+  "write_sketch_row",
   "set_row_visual",
   "design_plan",
   "apply_row_visual_nudge",
@@ -1354,7 +1355,7 @@ function ChatTab({ focusMode = false }: { focusMode?: boolean }) {
       });
     } catch (err) {
       if (abortedRef.current || activePreflightSendIdRef.current !== sendId) return;
-      const errMsg = err instanceof Error ? err.message : String(err);
+      const errMsg = errorMessage(err);
       setChatError(errMsg);
       // Log error to activity
       addActivityEntries([{
@@ -1,6 +1,9 @@
 import { useRef, useEffect } from "react";
 import { AlertTriangle, X, Monitor } from "lucide-react";
 import { useAppStore } from "../stores/appStore";
+import { useToastStore } from "../stores/toastStore";
+import { listen } from "../services/tauri";
+import type { RecordedSession } from "../types/recording";
 import { ActionCard } from "./ActionCard";
 
 export function RecordingPanel() {
@@ -46,6 +49,25 @@ export function RecordingPanel() {
     return () => document.removeEventListener("visibilitychange", onVisible);
   }, [isBrowserReady, currentProject, selectedProfile, checkBrowsersRunning]);
 
+  // The backend warns about, then stops, takes with no captured actions
+  useEffect(() => {
+    if (!isRecording) return;
+    const unlistenIdle = listen<{ idle_seconds: number; auto_stop_in_seconds: number | null }>("recording-idle", (event) => {
+      const { idle_seconds, auto_stop_in_seconds } = event.payload;
+      const minutes = Math.round(idle_seconds / 60);
+      const stopNote = auto_stop_in_seconds != null ? ` It will stop in ${auto_stop_in_seconds}s unless you interact with the page.` : "";
+      useToastStore.getState().show(`No actions captured for ${minutes} min.${stopNote}`, 8000, "warning");
+    });
+    const unlistenStopped = listen<RecordedSession>("recording-auto-stopped", (event) => {
+      useAppStore.getState().recordingAutoStopped(event.payload);
+      useToastStore.getState().show("Recording stopped after being idle. The take was saved.", 6000, "info");
+    });
+    return () => {
+      unlistenIdle.then((fn) => fn());
+      unlistenStopped.then((fn) => fn());
+    };
+  }, [isRecording]);
+
   // Auto-scroll to the bottom as new actions come in
   useEffect(() => {
     if (listRef.current) {
@@ -12,6 +12,12 @@ interface MonitorInfo {
   is_primary: boolean;
 }
 
+/** Returned by open_capture_window when the chosen monitor was disconnected. */
+interface MissingMonitorWarning {
+  monitor_id: number;
+  monitors: MonitorInfo[];
+}
+
 interface ScreenCaptureOverlayProps {
   onCapture: (screenshotPath: string) => void;
   onCancel: () => void;
@@ -65,10 +71,15 @@ export function ScreenCaptureOverlay({ onCapture, onCancel }: ScreenCaptureOverl
       cleanupPreviewCaptures(new Set());
       callbacksRef.current.onCancel();
     });
+    const unlistenMonitors = listen<MonitorInfo[]>("monitors_changed", (event) => {
+      console.info(`[Overlay] monitors_changed: ${event.payload.length} monitor(s)`);
+      setMonitors(event.payload);
+    });
     return () => {
       console.info("[Overlay] Cleaning up event listeners");
       unlistenComplete.then((fn) => fn());
       unlistenCancel.then((fn) => fn());
+      unlistenMonitors.then((fn) => fn());
     };
   }, []);
 
@@ -151,7 +162,7 @@ export function ScreenCaptureOverlay({ onCapture, onCancel }: ScreenCaptureOverl
 
       // Open capture window on the target monitor
       console.info("[Overlay] Opening capture window...");
-      await invoke("open_capture_window", {
+      const warning = await invoke<MissingMonitorWarning | null>("open_capture_window", {
         monitorId: monitor.id,
         physX: monitor.x,
         physY: monitor.y,
@@ -160,6 +171,14 @@ export function ScreenCaptureOverlay({ onCapture, onCancel }: ScreenCaptureOverl
         bgPath: bgRelPath,
         projectRoot: project.root,
       });
+      if (warning) {
+        console.warn(`[Overlay] Monitor ${warning.monitor_id} is no longer connected`);
+        setMonitors(warning.monitors);
+        setWaitingForCapture(false);
+        setLoading(false);
+        if (warning.monitors.length === 0) onCancel();
+        return;
+      }
       console.info("[Overlay] Capture window opened successfully");
     } catch (err) {
       console.error("[Overlay] openCaptureOnMonitor failed:", err);
@@ -1842,6 +1842,26 @@ function ExportTab({
         </div>
       </fieldset>
 
+      {isWorkspace && (
+        <label className="flex flex-col gap-1 text-xs font-medium text-[rgb(var(--color-text))]">
+          <span>
+            Screenshot quality <span className="font-mono text-[rgb(var(--color-text-secondary))]">{settings.workspaceScreenshotQuality}</span>
+          </span>
+          <span className="text-[11px] font-normal leading-4 text-[rgb(var(--color-text-secondary))]">
+            JPEG quality for captured screenshots. Lower values produce smaller files.
+          </span>
+          <input
+            type="range"
+            min="10"
+            max="100"
+            step="5"
+            value={settings.workspaceScreenshotQuality}
+            onChange={(event) => updateSetting("workspaceScreenshotQuality", Number(event.target.value))}
+            className="mt-1 block w-full accent-[rgb(var(--color-accent))]"
+          />
+        </label>
+      )}
+
       <fieldset className="flex flex-col gap-3 rounded-xl border border-[rgb(var(--color-border))] bg-[rgb(var(--color-surface-alt))]/40 p-4">
         <div>
           <label className="text-sm font-medium">Sketch video timing</label>
@@ -4417,6 +4437,23 @@ function RepositoryTab({ settings, updateSetting }: {
           />
         </div>
       </fieldset>
+
+      <fieldset className="flex flex-col gap-2">
+        <label className="flex items-center justify-between gap-3">
+          <span>
+            <span className="block text-sm font-medium text-[rgb(var(--color-text))]">Normalize line endings</span>
+            <span className="text-xs text-[rgb(var(--color-text-secondary))]">
+              Save notes with LF line endings so CRLF edits on Windows don't show up as changes.
+            </span>
+          </span>
+          <input
+            type="checkbox"
+            checked={settings.workspaceNormalizeLineEndings}
+            onChange={(e) => updateSetting("workspaceNormalizeLineEndings", e.target.checked)}
+            className="h-4 w-4 accent-[rgb(var(--color-accent))]"
+          />
+        </label>
+      </fieldset>
     </div>
   );
 }
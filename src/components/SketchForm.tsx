@@ -3,7 +3,7 @@ import { Channel, convertFileSrc, invoke, listen } from "../services/tauri";
 import { open, save } from "@tauri-apps/plugin-dialog";
 import { openPath } from "@tauri-apps/plugin-opener";
 import { ChevronLeft, Sparkles, Monitor, Plus, X, Folder, Check, Film, Image as ImageIcon, Mic2 } from "lucide-react";
-import { shouldSuppressEditorFlush, useAppStore } from "../stores/appStore";
+import { errorMessage, shouldSuppressEditorFlush, useAppStore } from "../stores/appStore";
 import { useToastStore } from "../stores/toastStore";
 import { useSettings } from "../hooks/useSettings";
 import { useBackgroundAgentAction } from "../hooks/useBackgroundAgentAction";
@@ -1962,8 +1962,8 @@ Rules:
       useToastStore.getState().show(`Generated AI narration for ${generatedCount} row${generatedCount === 1 ? "" : "s"}`, 5000, "success");
     } catch (err) {
       console.warn("[SketchForm] Failed to generate sketch narration:", err);
-      useToastStore.getState().show(`Could not generate sketch narration: ${err}`, 7000, "error");
-      await refreshAfterNarrationGeneration(`AI narration generation failed: ${err}`, "error");
+      useToastStore.getState().show(`Could not generate sketch narration: ${errorMessage(err)}`, 7000, "error");
+      await refreshAfterNarrationGeneration(`AI narration generation failed: ${errorMessage(err)}`, "error");
     } finally {
       unlisten?.();
       setNarrationSavingRows((rows) => {
@@ -2272,7 +2272,7 @@ Rules:
       await handleExportVideo(rowsForExport, outputPath);
     } catch (err) {
       console.warn("[SketchForm] Failed to generate video:", err);
-      useToastStore.getState().show(`Could not generate video: ${err}`, 7000, "error");
+      useToastStore.getState().show(`Could not generate video: ${errorMessage(err)}`, 7000, "error");
       setVideoExportProgress(null);
     }
   }, [
@@ -1228,6 +1228,8 @@ function mockInvoke(cmd: string, args?: Record<string, unknown>): unknown {
       return { 0: ".cutready/screenshots/mock-monitor.png" };
     case "capture_fullscreen":
       return ".cutready/screenshots/mock-monitor.png";
+    case "copy_screenshot_to_clipboard":
+      return null;
     case "open_recording_countdown_window":
       window.setTimeout(() => {
         (window as any).__TAURI_INTERNALS__?.emit?.("recording-countdown-complete", {
@@ -1,6 +1,6 @@
 import { useCallback, useMemo } from "react";
 import { BUILT_IN_AGENTS, resolveAgentPrompt } from "../agents/builtInAgents";
-import { clearSuppressedEditorFlush, suppressEditorFlush, useAppStore } from "../stores/appStore";
+import { clearSuppressedEditorFlush, errorMessage, suppressEditorFlush, useAppStore } from "../stores/appStore";
 import { useToastStore } from "../stores/toastStore";
 import { useAiApplyGateStore } from "../stores/aiApplyGateStore";
 import { useSettings, type AgentPreset } from "./useSettings";
@@ -27,6 +27,7 @@ interface BackgroundAgentActionOptions {
 
 const ROW_TARGETED_SKETCH_MUTATION_TOOLS = new Set([
   "update_planning_row",
+  "write_sketch_row",
   "set_row_visual",
   "design_plan",
   "apply_row_visual_nudge",
@@ -279,7 +280,7 @@ export function useBackgroundAgentAction() {
       }]);
       showToast(`${label} complete`, 3000, "success");
     } catch (err) {
-      const message = err instanceof Error ? err.message : String(err);
+      const message = errorMessage(err);
       addActivityEntries([{
         id: crypto.randomUUID(),
         timestamp: new Date(),
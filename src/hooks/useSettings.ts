@@ -298,6 +298,10 @@ export interface WorkspaceSettings {
   workspaceVideoExportBackgroundMusicDuckNarration: boolean;
   /** Background music fade in/out duration in seconds. */
   workspaceVideoExportBackgroundMusicFadeSeconds: number;
+  /** JPEG quality (1–100) for screenshots captured in this workspace. */
+  workspaceScreenshotQuality: number;
+  /** Write notes with LF line endings so hashes match across platforms. */
+  workspaceNormalizeLineEndings: boolean;
 }
 
 /** Combined view for backward compatibility — consumers that need both. */
@@ -436,6 +440,8 @@ export const defaultWorkspaceSettings: WorkspaceSettings = {
   workspaceVideoExportBackgroundMusicVolumeDb: -24,
   workspaceVideoExportBackgroundMusicDuckNarration: true,
   workspaceVideoExportBackgroundMusicFadeSeconds: 0.5,
+  workspaceScreenshotQuality: 90,
+  workspaceNormalizeLineEndings: false,
 };
 
 const defaultSettings: AppSettings = {
@@ -499,6 +505,8 @@ const WORKSPACE_KEYS: (keyof WorkspaceSettings)[] = [
   "workspaceVideoExportBackgroundMusicVolumeDb",
   "workspaceVideoExportBackgroundMusicDuckNarration",
   "workspaceVideoExportBackgroundMusicFadeSeconds",
+  "workspaceScreenshotQuality",
+  "workspaceNormalizeLineEndings",
 ];
 
 function providerLabel(provider: AiProviderKind): string {
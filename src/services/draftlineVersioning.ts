@@ -43,7 +43,7 @@ import {
   type WorkspaceGraphRef,
 } from "@draftline/client";
 import { invoke, listen } from "./tauri";
-import type { ConflictFile, DiffEntry, GraphNode, IncomingCommit, RemoteBranchInfo, RemoteInfo, SyncStatus, TimelineInfo, VersionEntry } from "../types/sketch";
+import type { ConflictFile, DiffEntry, GraphNode, IncomingCommit, RemoteBranchInfo, RemoteInfo, SyncStatus, TimelineInfo, VersionEntry, VersionTag } from "../types/sketch";
 
 export type DraftlineMergeIncomingToken = MergeIncomingToken;
 export type DraftlineMergeConflictResolution = MergeConflictResolution;
@@ -144,6 +144,25 @@ export function setDraftlineWorkspacePath(path: string | null) {
   cachedFacadePath = null;
 }
 
+/**
+ * Adapter-backed commands reject with `{ code, message, details }`; callers
+ * show the rejection as text, so surface just the message.
+ */
+export function draftlineErrorText(error: unknown): string {
+  if (error && typeof error === "object" && "message" in error && typeof error.message === "string") {
+    return error.message;
+  }
+  return String(error);
+}
+
+async function withErrorText<T>(call: Promise<T>): Promise<T> {
+  try {
+    return await call;
+  } catch (error) {
+    throw draftlineErrorText(error);
+  }
+}
+
 function facade(): DraftlineHostFacade {
   if (!draftlineWorkspacePath) {
     throw new Error("No Draftline workspace is currently open");
@@ -178,6 +197,7 @@ export async function listDraftlineGraphNodes(): Promise<GraphNode[]> {
   });
   const laneByVariation = new Map(variations.map((entry, index) => [entry.variation.id, index]));
   const refsByVersion = refsByTargetVersion(graph.refs);
+  const tagsByVersion = tagsByTargetVersion(await listDraftlineVersionTags().catch(() => []));
 
   return graph.nodes.map((node) => {
     const refs = refsByVersion.get(node.version.id) ?? [];
@@ -204,13 +224,17 @@ export async function listDraftlineGraphNodes(): Promise<GraphNode[]> {
         ? { reachable_from_support_ref: node.reachable_from_support_ref }
         : {}),
       author: node.version.author.name,
+      ...(tagsByVersion.has(node.version.id) ? { tags: tagsByVersion.get(node.version.id) } : {}),
     };
   });
 }
 
 export async function listDraftlineTimelines(): Promise<TimelineInfo[]> {
   const variations = await facade().variations();
-  return variations.map((summary, index) => variationToTimeline(summary, index));
+  // Adopted repos may still use `master` as their default branch. Show it as
+  // "main" until it is renamed; its name stays `master` so switching works.
+  const legacyDefault = !variations.some((summary) => summary.variation.id === "main");
+  return variations.map((summary, index) => variationToTimeline(summary, index, legacyDefault));
 }
 
 export async function previewDraftlineVersion(version: string): Promise<DiffEntry[]> {
@@ -320,9 +344,9 @@ export async function deleteDraftlineVariation(variation: string): Promise<void>
   if (!draftlineWorkspacePath) {
     throw new Error("No Draftline workspace is currently open");
   }
-  await invoke("delete_variation", {
+  await withErrorText(invoke("delete_variation", {
     request: { workspace_path: draftlineWorkspacePath, variation },
-  });
+  }));
 }
 
 export async function preflightDraftlineRenameVariation(
@@ -360,11 +384,11 @@ export async function renameDraftlineVariation(
 }
 
 export async function preflightDraftlineSwitchVariation(variation: string): Promise<PreflightReport> {
-  return facade().preflightSwitchVariation(variation);
+  return withErrorText(facade().preflightSwitchVariation(variation));
 }
 
 export async function switchDraftlineVariation(variation: string): Promise<SwitchVariationResult> {
-  return facade().switchVariation(variation);
+  return withErrorText(facade().switchVariation(variation));
 }
 
 export async function restoreDraftlineVersionAsNewSave(version: string, label: string): Promise<string> {
@@ -389,9 +413,9 @@ export async function addDraftlineRemote(name: string, url: string): Promise<Rem
   if (!draftlineWorkspacePath) {
     throw new Error("No Draftline workspace is currently open");
   }
-  const remote = await invoke<{ name: string; url: string }>("add_remote", {
+  const remote = await withErrorText(invoke<{ name: string; url: string }>("add_remote", {
     request: { workspace_path: draftlineWorkspacePath, name, url },
-  });
+  }));
   return { name: remote.name, url: remote.url };
 }
 
@@ -534,12 +558,40 @@ export async function squashDraftlineVersions(count: number, label: string): Pro
   if (!draftlineWorkspacePath) {
     throw new Error("No Draftline workspace is currently open");
   }
-  const version = await invoke<Version>("squash_versions", {
+  const version = await withErrorText(invoke<Version>("squash_versions", {
     request: { workspace_path: draftlineWorkspacePath, count, label },
-  });
+  }));
   return version.id;
 }
 
+export async function listDraftlineVersionTags(): Promise<VersionTag[]> {
+  if (!draftlineWorkspacePath) {
+    throw new Error("No Draftline workspace is currently open");
+  }
+  const tags = await invoke<VersionTag[] | undefined>("list_version_tags", {
+    request: { workspace_path: draftlineWorkspacePath },
+  });
+  return tags ?? [];
+}
+
+export async function tagDraftlineVersion(version: string, tag: string): Promise<VersionTag[]> {
+  if (!draftlineWorkspacePath) {
+    throw new Error("No Draftline workspace is currently open");
+  }
+  return invoke<VersionTag[]>("tag_version", {
+    request: { workspace_path: draftlineWorkspacePath, version_id: version, tag },
+  });
+}
+
+export async function deleteDraftlineVersionTag(tag: string): Promise<VersionTag[]> {
+  if (!draftlineWorkspacePath) {
+    throw new Error("No Draftline workspace is currently open");
+  }
+  return invoke<VersionTag[]>("delete_version_tag", {
+    request: { workspace_path: draftlineWorkspacePath, tag },
+  });
+}
+
 export async function listDraftlineSnapshotCleanupCandidates(
   selectedVersion: string,
   targetVariation?: string | null,
@@ -662,6 +714,19 @@ function refsByTargetVersion(refs: WorkspaceGraphRef[]): Map<string, WorkspaceGr
   return byVersion;
 }
 
+function tagsByTargetVersion(tags: VersionTag[]): Map<string, string[]> {
+  const byVersion = new Map<string, string[]>();
+  for (const { tag, version_id } of tags) {
+    const existing = byVersion.get(version_id);
+    if (existing) {
+      existing.push(tag);
+    } else {
+      byVersion.set(version_id, [tag]);
+    }
+  }
+  return byVersion;
+}
+
 function graphNodeTimeline(node: WorkspaceGraphNode, refs: WorkspaceGraphRef[], fallback: string): string {
   const localRef = refs.find((ref) => ref.kind === "local_variation" && ref.variation);
   return localRef?.variation ?? node.variation_tips[0] ?? fallback;
@@ -693,10 +758,16 @@ function versionToEntry(version: Version): VersionEntry {
   };
 }
 
-function variationToTimeline(summary: VariationSummary, index: number): TimelineInfo {
+function variationToTimeline(
+  summary: VariationSummary,
+  index: number,
+  legacyDefault: boolean,
+): TimelineInfo {
+  const label = summary.variation.metadata.label ?? summary.variation.name;
+  const isLegacyDefault = legacyDefault && summary.variation.id === "master" && label === "master";
   return {
     name: summary.variation.id,
-    label: summary.variation.metadata.label ?? summary.variation.name,
+    label: isLegacyDefault ? "main" : label,
     is_active: summary.variation.is_current,
     snapshot_count: summary.reachable_version_count,
     color_index: index,
@@ -970,6 +970,8 @@ interface AppStoreState {
   startRecording: () => Promise<void>;
   /** Stop the active recording (browser stays open). */
   stopRecording: () => Promise<void>;
+  /** Apply a take the backend stopped after it sat idle too long. */
+  recordingAutoStopped: (session: RecordedSession) => void;
 }
 
 // ── Layout persistence helpers ─────────────────────────────────
@@ -3769,6 +3771,15 @@ export const useAppStore = create<AppStoreState>((set, get) => ({
       set({ loading: false });
     }
   },
+
+  recordingAutoStopped: (session) => {
+    set({
+      isRecording: false,
+      recordingSessionId: null,
+      lastSession: session,
+      _activeChannel: null,
+    });
+  },
 }));
 
 // Expose store on window in dev mode for Playwright/debugging
@@ -275,6 +275,103 @@ describe("draftlineVersioning", () => {
     });
   });
 
+  it("surfaces version tags on matching graph nodes", async () => {
+    mockInvoke
+      .mockResolvedValueOnce([
+        {
+          variation: variation("main", "Main"),
+          head_version: version("1111111111111111111111111111111111111111", "First", 1_700_000_000),
+          reachable_version_count: 1,
+        },
+      ])
+      .mockResolvedValueOnce({
+        workspace_id: { root: WORKSPACE },
+        current_variation: "main",
+        current_version: "1111111111111111111111111111111111111111",
+        dirty: { is_dirty: false, files: [] },
+        recovery: null,
+        state_may_be_inconsistent: false,
+        snapshot_id: "snapshot-1",
+        was_pruned: false,
+        has_more: false,
+        nodes: [
+          {
+            id: "node-1111111111111111111111111111111111111111",
+            version: version("1111111111111111111111111111111111111111", "First", 1_700_000_000, "Seth"),
+            parent_ids: [],
+            parent_version_ids: [],
+            variation_tips: ["main"],
+            is_head: true,
+            is_current: true,
+            is_tip: true,
+            layout: { lane: 0, row: 0, display_label: "main" },
+          },
+        ],
+        refs: [],
+      })
+      .mockResolvedValueOnce([
+        { tag: "beta", version_id: "1111111111111111111111111111111111111111" },
+        { tag: "v1-shipped", version_id: "1111111111111111111111111111111111111111" },
+        { tag: "orphan", version_id: "9999999999999999999999999999999999999999" },
+      ]);
+
+    const nodes = await listDraftlineGraphNodes();
+
+    expect(nodes).toHaveLength(1);
+    expect(nodes[0].tags).toEqual(["beta", "v1-shipped"]);
+    expect(mockInvoke).toHaveBeenNthCalledWith(3, "list_version_tags", {
+      request: { workspace_path: WORKSPACE },
+    });
+  });
+
+  it("keeps every parent on merge graph nodes", async () => {
+    const merge = "3333333333333333333333333333333333333333";
+    const first = "1111111111111111111111111111111111111111";
+    const second = "2222222222222222222222222222222222222222";
+    const graphNode = (id: string, label: string, time: number, parents: string[], row: number, lane = 0) => ({
+      id: `node-${id}`,
+      version: version(id, label, time),
+      parent_ids: parents.map((parent) => `node-${parent}`),
+      parent_version_ids: parents,
+      variation_tips: row === 0 ? ["main"] : [],
+      is_head: row === 0,
+      is_current: row === 0,
+      is_tip: row === 0,
+      layout: { lane, row, display_label: row === 0 ? "main" : "" },
+    });
+    mockInvoke
+      .mockResolvedValueOnce([
+        {
+          variation: variation("main", "Main"),
+          head_version: version(merge, "Merge alt", 1_700_000_200),
+          reachable_version_count: 3,
+        },
+      ])
+      .mockResolvedValueOnce({
+        workspace_id: { root: WORKSPACE },
+        current_variation: "main",
+        current_version: merge,
+        dirty: { is_dirty: false, files: [] },
+        recovery: null,
+        state_may_be_inconsistent: false,
+        snapshot_id: "snapshot-1",
+        was_pruned: false,
+        has_more: false,
+        nodes: [
+          graphNode(merge, "Merge alt", 1_700_000_200, [first, second], 0),
+          graphNode(second, "Alt work", 1_700_000_100, [], 1, 1),
+          graphNode(first, "First", 1_700_000_000, [], 2),
+        ],
+        refs: [],
+      })
+      .mockResolvedValueOnce([]);
+
+    const nodes = await listDraftlineGraphNodes();
+
+    expect(nodes.map((node) => node.id)).toEqual([merge, second, first]);
+    expect(nodes[0].parents).toEqual([first, second]);
+  });
+
   it("maps Draftline variations onto timeline entries", async () => {
     mockInvoke.mockResolvedValueOnce([
       {
@@ -295,6 +392,26 @@ describe("draftlineVersioning", () => {
     ]);
   });
 
+  it("labels a legacy master default branch as main", async () => {
+    mockInvoke.mockResolvedValueOnce([
+      {
+        variation: variation("master"),
+        head_version: version("1111111111111111111111111111111111111111", "First", 1_700_000_000),
+        reachable_version_count: 2,
+      },
+      {
+        variation: variation("alt-take", "alt-take", false),
+        head_version: null,
+        reachable_version_count: 1,
+      },
+    ]);
+
+    await expect(listDraftlineTimelines()).resolves.toEqual([
+      { name: "master", label: "main", is_active: true, snapshot_count: 2, color_index: 0 },
+      { name: "alt-take", label: "alt-take", is_active: false, snapshot_count: 1, color_index: 1 },
+    ]);
+  });
+
   it("deletes a Draftline variation by typed id string", async () => {
     mockInvoke.mockResolvedValueOnce(undefined);
 
@@ -304,6 +421,16 @@ describe("draftlineVersioning", () => {
     });
   });
 
+  it("rejects with the message of a structured command error", async () => {
+    mockInvoke.mockRejectedValueOnce({
+      code: "draftline_error",
+      message: "variation alt is checked out",
+      details: null,
+    });
+
+    await expect(deleteDraftlineVariation("alt")).rejects.toBe("variation alt is checked out");
+  });
+
   it("creates a Draftline variation through remote-aware preflight and guarded create", async () => {
     const token = {
       operation_id: "create-feature",
@@ -14,6 +14,10 @@ export interface RecentProject {
   last_opened: string;
   /** In multi-project repos, the relative path of the last active project. */
   last_active_project?: string;
+  /** Whether the folder still exists on disk. */
+  exists?: boolean;
+  /** Pinned projects stay at the top of the list. */
+  pinned?: boolean;
 }
 
 /** An entry in the multi-project manifest. */
@@ -44,6 +44,22 @@ export interface RecordedSession {
   actions: CapturedAction[];
 }
 
+/** Payload of the throttled `recording-progress` event during a take. */
+export interface RecordingProgress {
+  count: number;
+  last_action_type: string;
+}
+
+/** Listing entry for a saved session, without its actions. */
+export interface SessionSummary {
+  id: string;
+  mode: RecordingMode;
+  sketch_path: string | null;
+  started_at: string;
+  ended_at: string | null;
+  action_count: number;
+}
+
 /** A browser profile detected on the system. */
 export interface BrowserProfile {
   /** Browser identifier ("msedge" or "chrome"). */
@@ -64,6 +80,15 @@ export interface BrowserRunningStatus {
   chrome: boolean;
 }
 
+/** Result of `recorder_readiness`: profiles, open browsers, and a usable channel. */
+export interface RecorderReadiness {
+  profiles: BrowserProfile[];
+  running: BrowserRunningStatus;
+  /** A browser with profiles whose window isn't open; null means use a fresh browser. */
+  recommended_channel: string | null;
+  reason: string;
+}
+
 export type RecordingScope =
   | { kind: "sketch"; path: string }
   | { kind: "storyboard"; path: string };
@@ -172,6 +197,8 @@ export interface RecordingAssetRef {
   /** Path relative to the take directory. */
   path: string;
   status: RecordingAssetStatus;
+  /** Last ffprobe result for this file, when probed. */
+  probe?: RecordingProbe;
 }
 
 export interface RecordingMarker {
@@ -180,6 +207,21 @@ export interface RecordingMarker {
   label: string;
 }
 
+export type TrackKind = "video" | "audio" | "other";
+
+/** One stream reported by ffprobe for a media file. */
+export interface TrackInfo {
+  index: number;
+  kind: TrackKind;
+  codec: string | null;
+  title: string | null;
+}
+
+export interface RecordingProbe {
+  duration_ms: number;
+  tracks: TrackInfo[];
+}
+
 export type RecordingTakeStatus = "prepared" | "recording" | "finalized" | "failed";
 
 export interface RecordingTake {
@@ -145,6 +145,10 @@ export interface SketchSummary {
   title: string;
   state: SketchState;
   row_count: number;
+  /** Words across the description, narrative, and demo actions. */
+  word_count?: number;
+  /** First non-empty row narrative as plain text, for list subtitles. */
+  preview?: string | null;
   created_at: string;
   updated_at: string;
 }
@@ -210,6 +214,14 @@ export interface GraphNode {
   reachable_from_support_ref?: boolean;
   /** Author name (for collaborator info). */
   author?: string;
+  /** Milestone tags attached to this commit. */
+  tags?: string[];
+}
+
+/** A named milestone attached to a saved version. */
+export interface VersionTag {
+  tag: string;
+  version_id: string;
 }
 
 /** Remote git info. */